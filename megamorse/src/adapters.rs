@@ -0,0 +1,364 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::MorseCode;
+use crate::MorseDecoder;
+
+/// Extension methods to wrap a [MorseDecoder] in one of the adapters of this
+/// crate. Implemented for every decoder.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{Period, Recorder};
+/// use megamorse::{MorseDecoderExt, MorsePlayer, Null};
+///
+/// #[derive(Debug)]
+/// struct HalError;
+///
+/// // An active-low output at twice the time resolution, with a second output
+/// // switched along with it
+/// let decoder = Recorder::new()
+///     .invert()
+///     .scale_units(2)
+///     .map_err(|_| HalError)
+///     .tee(Null);
+///
+/// let mut player = MorsePlayer::new(decoder);
+///
+/// player.play_str("ET").unwrap();
+///
+/// let recorder = player.decoder().decoder().decoder().decoder().decoder();
+///
+/// assert_eq!(
+///     recorder.timeline(),
+///     [Period::Off(2), Period::On(6), Period::Off(6)],
+/// );
+/// ```
+pub trait MorseDecoderExt: MorseDecoder + Sized {
+    /// Switch `output` along with this decoder. See [Tee].
+    fn tee<O: KeyOutput>(self, output: O) -> Tee<Self, O> {
+        Tee::new(self, output)
+    }
+
+    /// Convert the errors of this decoder with `f`. See [MapErr].
+    fn map_err<E, F: FnMut(Self::Error) -> E>(self, f: F) -> MapErr<Self, F> {
+        MapErr::new(self, f)
+    }
+
+    /// Multiply every period by `factor`. See [ScaleUnits].
+    fn scale_units(self, factor: usize) -> ScaleUnits<Self> {
+        ScaleUnits::new(self, factor)
+    }
+
+    /// Swap on and off. See [Invert].
+    fn invert(self) -> Invert<Self> {
+        Invert::new(self)
+    }
+}
+
+impl<T: MorseDecoder> MorseDecoderExt for T {}
+
+/// Trait representing an output that follows the key without keeping time
+/// itself, such as an LED or a sidetone oscillator next to the keyed output.
+///
+/// Used by a [Tee] to switch a second output along with a [MorseDecoder].
+/// Unlike a decoder, the output must not wait: it is switched on right before
+/// the decoder starts a mark, and off right after the decoder returns.
+pub trait KeyOutput {
+    /// The error type that the output can return.
+    type Error;
+
+    /// Switch the output on (`true`) or off (`false`), and return straight
+    /// away.
+    fn set_output(&mut self, on: bool) -> Result<(), Self::Error>;
+}
+
+/// Errors returned by a [Tee].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TeeError<DecoderError, OutputError> {
+    /// An error occurred in the decoder.
+    Decoder(DecoderError),
+
+    /// An error occurred in the output.
+    Output(OutputError),
+}
+
+impl<D: fmt::Display, O: fmt::Display> fmt::Display for TeeError<D, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TeeError::Decoder(error) => write!(f, "decoder error: {}", error),
+            TeeError::Output(error) => write!(f, "output error: {}", error),
+        }
+    }
+}
+
+impl<D, O> core::error::Error for TeeError<D, O>
+where
+    D: core::error::Error + 'static,
+    O: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            TeeError::Decoder(error) => Some(error),
+            TeeError::Output(error) => Some(error),
+        }
+    }
+}
+
+/// A [MorseDecoder] that switches a second [KeyOutput] along with a decoder,
+/// for example to light an LED while a buzzer sounds.
+///
+/// Only the decoder keeps time. The output is switched on right before every
+/// mark is passed to the decoder, and off as soon as the decoder returns, so
+/// both follow the same timing. An error in the output stops the mark from
+/// reaching the decoder.
+///
+/// Two blocking decoders cannot be combined this way, as the second one would
+/// only start once the first one has waited out the whole period, doubling
+/// the length of every period.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{assert_notation, Recorder};
+/// use megamorse::{KeyOutput, MorseDecoderExt, MorsePlayer};
+///
+/// #[derive(Default)]
+/// struct Led {
+///     switches: Vec<bool>,
+/// }
+///
+/// impl KeyOutput for Led {
+///     type Error = ();
+///
+///     fn set_output(&mut self, on: bool) -> Result<(), Self::Error> {
+///         self.switches.push(on);
+///         Ok(())
+///     }
+/// }
+///
+/// let mut player = MorsePlayer::new(Recorder::new().tee(Led::default()));
+///
+/// player.play_str("EE").unwrap();
+///
+/// assert_notation(player.decoder().decoder().timeline(), ". .");
+/// assert_eq!(player.decoder().output().switches, [true, false, true, false]);
+/// ```
+#[derive(Debug)]
+pub struct Tee<D, O> {
+    decoder: D,
+    output: O,
+}
+
+impl<D: MorseDecoder, O: KeyOutput> Tee<D, O> {
+    /// Play everything through `decoder`, and switch `output` along with it.
+    pub fn new(decoder: D, output: O) -> Self {
+        Tee { decoder, output }
+    }
+
+    /// Returns a reference to the decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Returns a reference to the output.
+    pub fn output(&self) -> &O {
+        &self.output
+    }
+
+    /// Consumes the tee, returning the decoder and the output.
+    pub fn into_parts(self) -> (D, O) {
+        (self.decoder, self.output)
+    }
+
+    /// Plays a mark with `play`, with the output switched on around it.
+    fn mark(
+        &mut self,
+        play: impl FnOnce(&mut D) -> Result<(), D::Error>,
+    ) -> Result<(), TeeError<D::Error, O::Error>> {
+        self.output.set_output(true).map_err(TeeError::Output)?;
+        play(&mut self.decoder).map_err(TeeError::Decoder)?;
+        self.output.set_output(false).map_err(TeeError::Output)
+    }
+}
+
+impl<D: MorseDecoder, O: KeyOutput> MorseDecoder for Tee<D, O> {
+    type Error = TeeError<D::Error, O::Error>;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.mark(|decoder| decoder.on(timeunits))
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        self.mark(|decoder| decoder.on_code(code))
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.decoder.off(timeunits).map_err(TeeError::Decoder)
+    }
+}
+
+/// A [MorseDecoder] that converts the errors of another decoder with a
+/// function, for example to translate a HAL error type.
+#[derive(Debug)]
+pub struct MapErr<D, F> {
+    decoder: D,
+    f: F,
+}
+
+impl<D: MorseDecoder, F> MapErr<D, F> {
+    /// Wrap `decoder`, converting its errors with `f`.
+    pub fn new(decoder: D, f: F) -> Self {
+        MapErr { decoder, f }
+    }
+
+    /// Returns a reference to the wrapped decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Consumes the wrapper, returning the wrapped decoder.
+    pub fn into_decoder(self) -> D {
+        self.decoder
+    }
+}
+
+impl<D, F, E> MorseDecoder for MapErr<D, F>
+where
+    D: MorseDecoder,
+    F: FnMut(D::Error) -> E,
+{
+    type Error = E;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.decoder.on(timeunits).map_err(&mut self.f)
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        self.decoder.on_code(code).map_err(&mut self.f)
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.decoder.off(timeunits).map_err(&mut self.f)
+    }
+}
+
+/// A [MorseDecoder] that multiplies every period by a constant factor before
+/// passing it on, so that the wrapped decoder can work with a finer time unit.
+/// Periods that overflow are passed as `usize::MAX`. Dots and dashes are
+/// passed on as plain marks of the scaled length, so a dash ratio of the
+/// wrapped decoder does not apply.
+#[derive(Debug)]
+pub struct ScaleUnits<D> {
+    decoder: D,
+    factor: usize,
+}
+
+impl<D: MorseDecoder> ScaleUnits<D> {
+    /// Wrap `decoder`, multiplying every period by `factor`.
+    pub fn new(decoder: D, factor: usize) -> Self {
+        ScaleUnits { decoder, factor }
+    }
+
+    /// Returns a reference to the wrapped decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Consumes the wrapper, returning the wrapped decoder.
+    pub fn into_decoder(self) -> D {
+        self.decoder
+    }
+}
+
+impl<D: MorseDecoder> MorseDecoder for ScaleUnits<D> {
+    type Error = D::Error;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.decoder.on(timeunits.saturating_mul(self.factor))
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        // A scaled dash is no longer 3 time units of the wrapped decoder, so
+        // it cannot be passed on as a code
+        self.on(code.timeunits())
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.decoder.off(timeunits.saturating_mul(self.factor))
+    }
+}
+
+/// A [MorseDecoder] that swaps on and off, for hardware that is active-low
+/// or for keying a signal that is normally on. Dots and dashes become spaces
+/// of their standard length, so a dash ratio of the wrapped decoder does not
+/// apply.
+#[derive(Debug)]
+pub struct Invert<D> {
+    decoder: D,
+}
+
+impl<D: MorseDecoder> Invert<D> {
+    /// Wrap `decoder`, swapping on and off.
+    pub fn new(decoder: D) -> Self {
+        Invert { decoder }
+    }
+
+    /// Returns a reference to the wrapped decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Consumes the wrapper, returning the wrapped decoder.
+    pub fn into_decoder(self) -> D {
+        self.decoder
+    }
+}
+
+impl<D: MorseDecoder> MorseDecoder for Invert<D> {
+    type Error = D::Error;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.decoder.off(timeunits)
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        // The code turns into a space, which has no dots or dashes
+        self.decoder.off(code.timeunits())
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.decoder.on(timeunits)
+    }
+}
+
+/// A [MorseDecoder] and [KeyOutput] that does nothing, and never fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Null;
+
+impl KeyOutput for Null {
+    type Error = Infallible;
+
+    fn set_output(&mut self, _on: bool) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl MorseDecoder for Null {
+    type Error = Infallible;
+
+    fn on(&mut self, _timeunits: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn on_code(&mut self, _code: MorseCode) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn off(&mut self, _timeunits: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+use crate::str_units;
+use crate::Clock;
+use crate::InvalidCharacterPolicy;
+use crate::MorseCode;
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::MorseSession;
+use crate::MorseWord;
+use crate::PlaybackControl;
+
+const M: MorseWord = MorseWord::new([MorseCode::Dash, MorseCode::Dash]);
+const O: MorseWord = MorseWord::new([MorseCode::Dash, MorseCode::Dash, MorseCode::Dash]);
+
+/// The standard ARDF fox identifiers, indexed by fox number minus one.
+const IDENTIFIERS: [(&str, MorseWord); 5] = [
+    ("MOE", MorseWord::new([MorseCode::Dot])),
+    ("MOI", MorseWord::new([MorseCode::Dot, MorseCode::Dot])),
+    (
+        "MOS",
+        MorseWord::new([MorseCode::Dot, MorseCode::Dot, MorseCode::Dot]),
+    ),
+    (
+        "MOH",
+        MorseWord::new([
+            MorseCode::Dot,
+            MorseCode::Dot,
+            MorseCode::Dot,
+            MorseCode::Dot,
+        ]),
+    ),
+    (
+        "MO5",
+        MorseWord::new([
+            MorseCode::Dot,
+            MorseCode::Dot,
+            MorseCode::Dot,
+            MorseCode::Dot,
+            MorseCode::Dot,
+        ]),
+    ),
+];
+
+/// What a call to [Fox::poll] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoxEvent {
+    /// The fox is outside its slot, or the slot has too little time left for
+    /// another round. The next slot of the fox starts at the given time.
+    Idle {
+        /// The start time of the next slot, in milliseconds.
+        next_slot_ms: u64,
+    },
+
+    /// The fox sent one round of its identifier and carrier.
+    Transmitted,
+
+    /// A pause was requested through a [PlaybackControl], so nothing was
+    /// sent, even within the slot of the fox.
+    Paused,
+}
+
+/// A transmitter ("fox") for amateur radio direction finding.
+///
+/// ARDF foxes share a single frequency and take turns: a cycle is divided into
+/// one equal slot per fox, and fox 1 transmits in the first slot, fox 2 in the
+/// second, and so on. During its slot, a fox repeatedly sends its standard
+/// identifier (`MOE`, `MOI`, `MOS`, `MOH` or `MO5` for fox 1 to 5), each
+/// optionally followed by a continuous carrier to make bearings easier.
+///
+/// The default is the classic setup of 5 foxes with a 5 minute cycle. All
+/// foxes should share the same clock epoch, set with [Fox::with_start_ms].
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{notation, Recorder};
+/// use megamorse::{Fox, FoxEvent, MorsePlayer};
+///
+/// let mut player = MorsePlayer::new(Recorder::new());
+///
+/// // Fox 3 of 5, sending at a time unit of 120 ms with 40 units of carrier
+/// // after every identifier
+/// let fox = Fox::new(3).with_unit_ms(120).with_carrier(40);
+///
+/// assert_eq!(fox.identifier(), "MOS");
+/// assert!(!fox.is_transmitting(0));
+/// assert!(fox.is_transmitting(120_000));
+///
+/// assert_eq!(
+///     fox.poll(&|| 0, &mut player).unwrap(),
+///     FoxEvent::Idle { next_slot_ms: 120_000 }
+/// );
+/// assert_eq!(fox.poll(&|| 120_000, &mut player).unwrap(), FoxEvent::Transmitted);
+/// assert_eq!(notation(player.decoder().timeline()), "-- --- ... / {on 40}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fox {
+    number: u8,
+    foxes: u8,
+    cycle_ms: u64,
+    start_ms: u64,
+    unit_ms: u64,
+    carrier: usize,
+}
+
+impl Fox {
+    /// Create fox `number` of a 5 fox, 5 minute cycle, with a time unit of
+    /// 100 ms and no carrier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `number` is not between 1 and 5.
+    pub const fn new(number: u8) -> Self {
+        assert!(
+            number >= 1 && number <= 5,
+            "ARDF fox numbers range from 1 to 5"
+        );
+
+        Fox {
+            number,
+            foxes: 5,
+            cycle_ms: 5 * 60_000,
+            start_ms: 0,
+            unit_ms: 100,
+            carrier: 0,
+        }
+    }
+
+    /// Set the amount of foxes taking turns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `foxes` is lower than the number of this fox, higher than 5,
+    /// or higher than the cycle length in milliseconds.
+    pub const fn with_foxes(mut self, foxes: u8) -> Self {
+        assert!(
+            foxes >= self.number && foxes <= 5,
+            "fox number exceeds the amount of foxes"
+        );
+        assert!(
+            self.cycle_ms >= foxes as u64,
+            "cycle is too short to give every fox a slot"
+        );
+
+        self.foxes = foxes;
+        self
+    }
+
+    /// Set the length of a full cycle, in which every fox transmits once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cycle is shorter than one millisecond per fox, as the
+    /// slots would then be empty.
+    pub const fn with_cycle_ms(mut self, cycle_ms: u64) -> Self {
+        assert!(
+            cycle_ms >= self.foxes as u64,
+            "cycle is too short to give every fox a slot"
+        );
+
+        self.cycle_ms = cycle_ms;
+        self
+    }
+
+    /// Set the time at which the first cycle starts. Times before this are
+    /// outside of any slot.
+    pub const fn with_start_ms(mut self, start_ms: u64) -> Self {
+        self.start_ms = start_ms;
+        self
+    }
+
+    /// Set the length of a time unit of the decoder, used to check whether
+    /// another round fits in the slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit_ms` is zero.
+    pub const fn with_unit_ms(mut self, unit_ms: u64) -> Self {
+        assert!(unit_ms > 0, "a time unit cannot be zero milliseconds");
+
+        self.unit_ms = unit_ms;
+        self
+    }
+
+    /// Follow every identifier with a continuous carrier of the given amount
+    /// of time units.
+    pub const fn with_carrier(mut self, timeunits: usize) -> Self {
+        self.carrier = timeunits;
+        self
+    }
+
+    /// The number of this fox, from 1 to 5.
+    pub const fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// The identifier of this fox, such as `"MOE"`.
+    pub const fn identifier(&self) -> &'static str {
+        IDENTIFIERS[self.number as usize - 1].0
+    }
+
+    /// The identifier of this fox as [MorseWord]s.
+    pub const fn identifier_words(&self) -> [MorseWord; 3] {
+        [M, O, IDENTIFIERS[self.number as usize - 1].1]
+    }
+
+    /// The length of the transmit slot of every fox.
+    pub const fn slot_ms(&self) -> u64 {
+        self.cycle_ms / self.foxes as u64
+    }
+
+    /// The start and end time of the slot of this fox in the cycle that
+    /// contains `now_ms`, which may lie in the past or the future.
+    fn slot_in_cycle(&self, now_ms: u64) -> (u64, u64) {
+        let cycles = now_ms.saturating_sub(self.start_ms) / self.cycle_ms;
+        let cycle_start = self.start_ms + cycles * self.cycle_ms;
+        let start = cycle_start + (self.number as u64 - 1) * self.slot_ms();
+
+        (start, start + self.slot_ms())
+    }
+
+    /// Returns true if `now_ms` lies within a slot of this fox.
+    pub fn is_transmitting(&self, now_ms: u64) -> bool {
+        let (start, end) = self.slot_in_cycle(now_ms);
+
+        now_ms >= self.start_ms && now_ms >= start && now_ms < end
+    }
+
+    /// The start time of the first slot of this fox that starts at or after
+    /// `now_ms`.
+    pub fn next_slot_ms(&self, now_ms: u64) -> u64 {
+        let (start, _) = self.slot_in_cycle(now_ms);
+
+        if start >= now_ms {
+            start
+        } else {
+            start + self.cycle_ms
+        }
+    }
+
+    /// The amount of time units one round of identifier and carrier takes,
+    /// including the word pause that follows it.
+    pub fn round_units(&self) -> usize {
+        // Counting cannot fail, as the identifier is valid
+        let mut units =
+            str_units(self.identifier(), InvalidCharacterPolicy::Reject).unwrap_or_default() + 7;
+
+        if self.carrier > 0 {
+            units += self.carrier + 7;
+        }
+
+        units
+    }
+
+    /// Send one round of identifier and carrier, if the current time lies
+    /// within the slot of this fox and the round fits in the remaining time.
+    ///
+    /// This blocks for as long as the round takes. Call it in a loop to fill
+    /// the slot.
+    pub fn poll<T: MorseDecoder>(
+        &self,
+        clock: &impl Clock,
+        player: &mut MorsePlayer<T>,
+    ) -> Result<FoxEvent, MorsePlayerError<T::Error>> {
+        self.poll_controlled(clock, player, None)
+    }
+
+    /// Send one round of identifier and carrier like [Fox::poll], while
+    /// allowing it to be aborted or held back through a [PlaybackControl].
+    ///
+    /// An abort request is honoured before the next element, including the
+    /// carrier and the pause after it, and makes this method return
+    /// [MorsePlayerError::Aborted]. While a pause is requested, no round is
+    /// started and [FoxEvent::Paused] is returned.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::Recorder;
+    /// use megamorse::{Fox, FoxEvent, MorsePlayer, MorsePlayerError, PlaybackControl};
+    ///
+    /// let control = PlaybackControl::new();
+    /// let mut player = MorsePlayer::new(Recorder::new());
+    /// let fox = Fox::new(1).with_unit_ms(120);
+    ///
+    /// control.pause();
+    /// assert_eq!(fox.poll_with(&|| 0, &mut player, &control).unwrap(), FoxEvent::Paused);
+    ///
+    /// control.reset();
+    /// control.abort();
+    /// assert!(matches!(
+    ///     fox.poll_with(&|| 0, &mut player, &control),
+    ///     Err(MorsePlayerError::Aborted)
+    /// ));
+    /// assert!(player.decoder().timeline().is_empty());
+    /// ```
+    pub fn poll_with<T: MorseDecoder>(
+        &self,
+        clock: &impl Clock,
+        player: &mut MorsePlayer<T>,
+        control: &PlaybackControl,
+    ) -> Result<FoxEvent, MorsePlayerError<T::Error>> {
+        self.poll_controlled(clock, player, Some(control))
+    }
+
+    fn poll_controlled<T: MorseDecoder>(
+        &self,
+        clock: &impl Clock,
+        player: &mut MorsePlayer<T>,
+        control: Option<&PlaybackControl>,
+    ) -> Result<FoxEvent, MorsePlayerError<T::Error>> {
+        if control.is_some_and(PlaybackControl::is_paused) {
+            return Ok(FoxEvent::Paused);
+        }
+
+        let now = clock.now_ms();
+        let (_, end) = self.slot_in_cycle(now);
+        let round_ms = self.round_units() as u64 * self.unit_ms;
+
+        if !self.is_transmitting(now) || now + round_ms > end {
+            return Ok(FoxEvent::Idle {
+                next_slot_ms: self.next_slot_ms(now + 1),
+            });
+        }
+
+        let mut session = MorseSession::new(player, control);
+        session.push_str(self.identifier())?;
+        session.end()?;
+
+        if self.carrier > 0 {
+            player.on(self.carrier, control)?;
+            player.off(7, control)?;
+        }
+
+        Ok(FoxEvent::Transmitted)
+    }
+}
@@ -0,0 +1,342 @@
+use crate::Clock;
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::MorseSession;
+use crate::PlaybackControl;
+
+/// The station identification of a [Beacon], sent at least once every
+/// `interval_ms` milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Identification<'a> {
+    text: &'a str,
+    interval_ms: u64,
+}
+
+/// What a call to [Beacon::poll] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeaconEvent {
+    /// Nothing was due, so nothing was sent.
+    Idle,
+
+    /// A pause was requested through a [PlaybackControl], so nothing was
+    /// sent, even if it was due.
+    Paused,
+
+    /// A transmission was sent.
+    Transmitted {
+        /// Whether the station identification was sent.
+        id: bool,
+
+        /// Whether the beacon message was sent.
+        message: bool,
+    },
+
+    /// The beacon has sent its message the configured amount of times, and
+    /// will not transmit anymore.
+    Finished,
+}
+
+/// A scheduler that repeats a message on a fixed schedule, for radio beacons
+/// and repeaters.
+///
+/// The message is sent every `interval_ms` milliseconds, measured from the
+/// start of one transmission to the start of the next, optionally followed by
+/// a long continuous carrier. A station identification can be added, which is
+/// sent whenever its own timer expires, even if the message is not due. When
+/// both are due at once, the identification is sent first, followed by a word
+/// pause and the message.
+///
+/// The beacon does not keep time itself. Instead, [Beacon::poll] is called
+/// regularly with a [Clock], and plays whatever is due through a blocking
+/// [MorsePlayer]. Scheduling is based on when transmissions were due rather
+/// than when they were polled, so the schedule does not drift.
+///
+/// # Examples
+///
+/// Running a beacon against a simulated clock, recording what it sends:
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use core::cell::Cell;
+///
+/// use megamorse::testing::{notation, Recorder};
+/// use megamorse::{Beacon, BeaconEvent, MorsePlayer};
+///
+/// let time = Cell::new(0);
+/// let clock = || time.get();
+/// let mut player = MorsePlayer::new(Recorder::new());
+///
+/// // Send "VVV" every 30 seconds with a 5 second carrier, three times, and
+/// // identify at least every 10 minutes.
+/// let mut beacon = Beacon::new("VVV", 30_000)
+///     .with_carrier(5_000 / 60)
+///     .with_count(3)
+///     .with_id("DE N0CALL", 600_000);
+///
+/// assert_eq!(
+///     beacon.poll(&clock, &mut player).unwrap(),
+///     BeaconEvent::Transmitted { id: true, message: true }
+/// );
+///
+/// assert_eq!(
+///     notation(player.decoder().timeline()),
+///     "-.. . / -. ----- -.-. .- .-.. .-.. / ...- ...- ...- / {on 83}"
+/// );
+///
+/// assert_eq!(beacon.poll(&clock, &mut player).unwrap(), BeaconEvent::Idle);
+/// assert_eq!(beacon.next_due_ms(), Some(30_000));
+///
+/// time.set(30_000);
+/// assert_eq!(
+///     beacon.poll(&clock, &mut player).unwrap(),
+///     BeaconEvent::Transmitted { id: false, message: true }
+/// );
+///
+/// time.set(60_000);
+/// beacon.poll(&clock, &mut player).unwrap();
+///
+/// assert_eq!(beacon.poll(&clock, &mut player).unwrap(), BeaconEvent::Finished);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Beacon<'a> {
+    message: &'a str,
+    interval_ms: u64,
+    carrier: usize,
+    count: Option<u32>,
+    id: Option<Identification<'a>>,
+    sent: u32,
+    next_message_ms: Option<u64>,
+    next_id_ms: Option<u64>,
+}
+
+impl<'a> Beacon<'a> {
+    /// Create a beacon that sends `message` every `interval_ms` milliseconds,
+    /// forever, without a carrier or a separate identification.
+    pub const fn new(message: &'a str, interval_ms: u64) -> Self {
+        Beacon {
+            message,
+            interval_ms,
+            carrier: 0,
+            count: None,
+            id: None,
+            sent: 0,
+            next_message_ms: None,
+            next_id_ms: None,
+        }
+    }
+
+    /// Follow every message with a continuous carrier of the given amount of
+    /// time units, after a word pause.
+    ///
+    /// Unlike the intervals, the carrier is given in time units, as it is
+    /// played through the [MorsePlayer] like any other element, and only the
+    /// decoder knows how long a time unit is.
+    pub const fn with_carrier(mut self, timeunits: usize) -> Self {
+        self.carrier = timeunits;
+        self
+    }
+
+    /// Stop after sending the message `count` times.
+    pub const fn with_count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Send `id` at least once every `interval_ms` milliseconds, starting with
+    /// the first transmission.
+    pub const fn with_id(mut self, id: &'a str, interval_ms: u64) -> Self {
+        self.id = Some(Identification {
+            text: id,
+            interval_ms,
+        });
+        self
+    }
+
+    /// The amount of times the message has been sent.
+    pub const fn sent(&self) -> u32 {
+        self.sent
+    }
+
+    /// Returns true if the message has been sent the configured amount of
+    /// times.
+    pub fn is_finished(&self) -> bool {
+        self.count.is_some_and(|count| self.sent >= count)
+    }
+
+    /// The time at which the next transmission is due, or None if the beacon
+    /// is finished. Before the first poll, this is None as well, as the first
+    /// transmission is due at whatever time the beacon is first polled.
+    pub fn next_due_ms(&self) -> Option<u64> {
+        if self.is_finished() {
+            return None;
+        }
+
+        match (self.next_message_ms, self.next_id_ms) {
+            (Some(message), Some(id)) => Some(message.min(id)),
+            (message, id) => message.or(id),
+        }
+    }
+
+    /// Send whatever is due at the current time of `clock`.
+    ///
+    /// This blocks for as long as the transmission takes, and returns what
+    /// was sent. If the beacon is polled late, a missed transmission is sent
+    /// once, and the next one is due at the first slot of the schedule after
+    /// the current time. Missed slots are skipped rather than sent back to
+    /// back.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use core::cell::Cell;
+    ///
+    /// use megamorse::testing::Recorder;
+    /// use megamorse::{Beacon, BeaconEvent, MorsePlayer};
+    ///
+    /// let time = Cell::new(0);
+    /// let clock = || time.get();
+    /// let mut player = MorsePlayer::new(Recorder::new());
+    /// let mut beacon = Beacon::new("E", 1_000);
+    ///
+    /// beacon.poll(&clock, &mut player).unwrap();
+    ///
+    /// // The clock stalled for three and a half intervals
+    /// time.set(3_500);
+    ///
+    /// assert!(matches!(
+    ///     beacon.poll(&clock, &mut player).unwrap(),
+    ///     BeaconEvent::Transmitted { .. }
+    /// ));
+    /// assert_eq!(beacon.poll(&clock, &mut player).unwrap(), BeaconEvent::Idle);
+    /// assert_eq!(beacon.next_due_ms(), Some(4_000));
+    /// assert_eq!(beacon.sent(), 2);
+    /// ```
+    pub fn poll<T: MorseDecoder>(
+        &mut self,
+        clock: &impl Clock,
+        player: &mut MorsePlayer<T>,
+    ) -> Result<BeaconEvent, MorsePlayerError<T::Error>> {
+        self.poll_controlled(clock, player, None)
+    }
+
+    /// Send whatever is due at the current time of `clock`, while allowing
+    /// the transmission to be aborted or held back through a
+    /// [PlaybackControl].
+    ///
+    /// An abort request is honoured before the next element, including the
+    /// carrier, and makes this method return [MorsePlayerError::Aborted]. The
+    /// interrupted transmission still counts as sent. While a pause is
+    /// requested, nothing is started and [BeaconEvent::Paused] is returned, so
+    /// whatever is due is sent by the first poll after resuming.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::{assert_notation, Recorder};
+    /// use megamorse::{Beacon, BeaconEvent, MorsePlayer, PlaybackControl};
+    ///
+    /// let control = PlaybackControl::new();
+    /// let mut player = MorsePlayer::new(Recorder::new());
+    /// let mut beacon = Beacon::new("VVV", 30_000);
+    ///
+    /// control.pause();
+    /// assert_eq!(
+    ///     beacon.poll_with(&|| 0, &mut player, &control).unwrap(),
+    ///     BeaconEvent::Paused
+    /// );
+    ///
+    /// control.resume();
+    /// assert_eq!(
+    ///     beacon.poll_with(&|| 5_000, &mut player, &control).unwrap(),
+    ///     BeaconEvent::Transmitted { id: false, message: true }
+    /// );
+    /// assert_notation(player.decoder().timeline(), "...- ...- ...-");
+    /// ```
+    pub fn poll_with<T: MorseDecoder>(
+        &mut self,
+        clock: &impl Clock,
+        player: &mut MorsePlayer<T>,
+        control: &PlaybackControl,
+    ) -> Result<BeaconEvent, MorsePlayerError<T::Error>> {
+        self.poll_controlled(clock, player, Some(control))
+    }
+
+    fn poll_controlled<T: MorseDecoder>(
+        &mut self,
+        clock: &impl Clock,
+        player: &mut MorsePlayer<T>,
+        control: Option<&PlaybackControl>,
+    ) -> Result<BeaconEvent, MorsePlayerError<T::Error>> {
+        if self.is_finished() {
+            return Ok(BeaconEvent::Finished);
+        }
+
+        if control.is_some_and(PlaybackControl::is_paused) {
+            return Ok(BeaconEvent::Paused);
+        }
+
+        let now = clock.now_ms();
+        let message_due = *self.next_message_ms.get_or_insert(now);
+        let send_message = now >= message_due;
+
+        let send_id = match self.id {
+            Some(id) => {
+                let id_due = *self.next_id_ms.get_or_insert(now);
+
+                if now >= id_due {
+                    self.next_id_ms = Some(now.saturating_add(id.interval_ms));
+                    true
+                } else {
+                    false
+                }
+            }
+            None => false,
+        };
+
+        if !send_message && !send_id {
+            return Ok(BeaconEvent::Idle);
+        }
+
+        if send_message {
+            self.next_message_ms = Some(next_slot(message_due, self.interval_ms, now));
+            self.sent += 1;
+        }
+
+        let mut session = MorseSession::new(player, control);
+
+        if let Some(id) = self.id.filter(|_| send_id) {
+            session.push_str(id.text)?;
+            session.push_char(' ')?;
+        }
+
+        if send_message {
+            session.push_str(self.message)?;
+        }
+
+        if send_message && self.carrier > 0 {
+            session.end()?;
+            player.on(self.carrier, control)?;
+        }
+
+        Ok(BeaconEvent::Transmitted {
+            id: send_id,
+            message: send_message,
+        })
+    }
+}
+
+/// The first slot of a schedule that started at `due` and repeats every
+/// `interval_ms`, that comes after `now`.
+fn next_slot(due: u64, interval_ms: u64, now: u64) -> u64 {
+    match interval_ms {
+        0 => now,
+        _ => {
+            let missed = now.saturating_sub(due) / interval_ms;
+
+            due.saturating_add(missed.saturating_add(1).saturating_mul(interval_ms))
+        }
+    }
+}
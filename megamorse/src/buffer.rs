@@ -0,0 +1,207 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::MorseDecoder;
+use crate::MorsePlayerError;
+use crate::MorseSession;
+use crate::TickPlayer;
+
+/// A bounded type-ahead buffer in front of a player, holding text that has
+/// been typed but not yet sent.
+///
+/// Text can be pushed faster than it is sent, and unsent characters can still
+/// be edited with [SendBuffer::backspace] and [SendBuffer::clear]. A character
+/// counts as sent as soon as the player starts playing it.
+///
+/// The buffer holds up to `N` characters. Characters that have already been
+/// sent are kept for [SendBuffer::sent] until their space is needed for new
+/// input, while pending characters are never overwritten.
+///
+/// The buffer can feed a blocking [MorseSession] through
+/// [SendBuffer::send_next], or a [TickPlayer] through [SendBuffer::tick].
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{SendBuffer, TickPlayer};
+///
+/// let mut buffer = SendBuffer::<16>::new();
+/// let mut player = TickPlayer::new();
+///
+/// buffer.push_str("CQ TSET").unwrap();
+///
+/// // The first tick starts sending the "C"
+/// assert!(buffer.tick(&mut player).unwrap());
+///
+/// // Fix a typo before it is sent
+/// buffer.backspace();
+/// buffer.backspace();
+/// buffer.backspace();
+/// buffer.push_str("EST").unwrap();
+///
+/// assert!(buffer.sent().eq("C".chars()));
+/// assert!(buffer.pending().eq("Q TEST".chars()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SendBuffer<const N: usize> {
+    chars: [char; N],
+    /// Index of the oldest character that is still kept.
+    start: usize,
+    /// The amount of kept characters that have already been sent.
+    sent: usize,
+    /// The amount of kept characters, both sent and pending.
+    len: usize,
+    /// The amount of characters sent over the lifetime of the buffer.
+    sent_total: usize,
+}
+
+/// Error returned when pushing to a [SendBuffer] whose capacity is taken up
+/// by pending characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferFull;
+
+impl fmt::Display for BufferFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("send buffer is full")
+    }
+}
+
+impl core::error::Error for BufferFull {}
+
+impl<const N: usize> Default for SendBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> SendBuffer<N> {
+    /// Create a new, empty send buffer.
+    pub const fn new() -> Self {
+        SendBuffer {
+            chars: ['\0'; N],
+            start: 0,
+            sent: 0,
+            len: 0,
+            sent_total: 0,
+        }
+    }
+
+    fn get(&self, index: usize) -> char {
+        self.chars[(self.start + index) % N]
+    }
+
+    /// Add a character to the end of the pending text.
+    ///
+    /// Returns [BufferFull] if all `N` characters are pending.
+    pub fn push(&mut self, c: char) -> Result<(), BufferFull> {
+        if self.len == N {
+            if self.sent == 0 {
+                return Err(BufferFull);
+            }
+
+            // Forget the oldest sent character to make room
+            self.start = (self.start + 1) % N;
+            self.sent -= 1;
+            self.len -= 1;
+        }
+
+        self.chars[(self.start + self.len) % N] = c;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Add every character of a string to the end of the pending text.
+    ///
+    /// If the buffer fills up, the characters that fit are kept and
+    /// [BufferFull] is returned.
+    pub fn push_str(&mut self, s: &str) -> Result<(), BufferFull> {
+        s.chars().try_for_each(|c| self.push(c))
+    }
+
+    /// Remove the last pending character, and return it.
+    ///
+    /// Returns None if there are no pending characters. Characters that have
+    /// already been sent cannot be removed.
+    pub fn backspace(&mut self) -> Option<char> {
+        if self.len == self.sent {
+            return None;
+        }
+
+        self.len -= 1;
+
+        Some(self.get(self.len))
+    }
+
+    /// Remove all pending characters.
+    pub fn clear(&mut self) {
+        self.len = self.sent;
+    }
+
+    /// Returns an iterator over the characters that have not been sent yet.
+    pub fn pending(&self) -> impl Iterator<Item = char> + '_ {
+        (self.sent..self.len).map(|index| self.get(index))
+    }
+
+    /// The amount of characters that have not been sent yet.
+    pub fn pending_len(&self) -> usize {
+        self.len - self.sent
+    }
+
+    /// Returns an iterator over the most recently sent characters that are
+    /// still kept in the buffer, oldest first.
+    pub fn sent(&self) -> impl Iterator<Item = char> + '_ {
+        (0..self.sent).map(|index| self.get(index))
+    }
+
+    /// The amount of characters sent over the lifetime of the buffer.
+    pub fn sent_count(&self) -> usize {
+        self.sent_total
+    }
+
+    /// Returns true if there are no pending characters.
+    pub fn is_empty(&self) -> bool {
+        self.pending_len() == 0
+    }
+
+    /// Take the next pending character, marking it as sent.
+    fn take(&mut self) -> Option<char> {
+        if self.len == self.sent {
+            return None;
+        }
+
+        let c = self.get(self.sent);
+
+        self.sent += 1;
+        self.sent_total += 1;
+
+        Some(c)
+    }
+
+    /// Send the next pending character through a blocking session, and
+    /// return it. Returns None without playing anything if there are no
+    /// pending characters.
+    ///
+    /// The character is marked as sent before it is played, so it is not
+    /// retried if playing it fails.
+    pub fn send_next<T: MorseDecoder>(
+        &mut self,
+        session: &mut MorseSession<'_, T>,
+    ) -> Result<Option<char>, MorsePlayerError<T::Error>> {
+        let Some(c) = self.take() else {
+            return Ok(None);
+        };
+
+        session.push_char(c)?;
+
+        Ok(Some(c))
+    }
+
+    /// Advance a [TickPlayer] by one time unit, feeding it the next pending
+    /// character whenever it is ready for one.
+    ///
+    /// Returns whether the output should be on during this time unit.
+    pub fn tick(&mut self, player: &mut TickPlayer) -> Result<bool, MorsePlayerError<Infallible>> {
+        player.tick(|| self.take())
+    }
+}
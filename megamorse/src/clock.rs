@@ -0,0 +1,17 @@
+/// A source of the current time, used by schedulers such as [Beacon](crate::Beacon)
+/// to decide when to transmit.
+///
+/// The clock is injected so that schedules can run on any platform, and can
+/// be tested in simulation with a clock that is advanced by the decoder.
+/// Any `Fn() -> u64` closure returning milliseconds is a [Clock].
+pub trait Clock {
+    /// The current time in milliseconds, counted from an arbitrary but fixed
+    /// starting point. Must never go backwards.
+    fn now_ms(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    fn now_ms(&self) -> u64 {
+        self()
+    }
+}
@@ -0,0 +1,140 @@
+use crate::MorseCode;
+use crate::MorseDecoder;
+
+/// A [MorseDecoder] that merges consecutive periods of the same state into a
+/// single call to the wrapped decoder.
+///
+/// A player can produce several `off` calls in a row, for example a pause
+/// between characters followed by the rest of a word pause, or the pause that
+/// closes one message followed by the pause that opens the next. Behind this
+/// wrapper, the decoder sees exactly one call per level change, with the
+/// summed duration, so that it can for example sleep through a whole gap at
+/// once. Periods of zero time units are dropped.
+///
+/// A period is only passed on once the level changes, so the last period is
+/// held back until [Coalesce::flush] is called.
+///
+/// A dot or dash on its own is passed on with [MorseDecoder::on_code]. When
+/// it is merged with another mark, such as a carrier that directly follows
+/// it, the wrapped decoder gets a plain mark of the summed length instead.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{Period, Recorder};
+/// use megamorse::{Coalesce, MorseDecoder, MorsePlayer};
+///
+/// let mut player = MorsePlayer::new(Coalesce::new(Recorder::new()));
+///
+/// player.play_str("E").unwrap();
+/// player.decoder_mut().off(3).unwrap();
+/// player.decoder_mut().off(7).unwrap();
+/// player.play_str("E").unwrap();
+/// player.decoder_mut().flush().unwrap();
+///
+/// assert_eq!(
+///     player.decoder().decoder().timeline(),
+///     [Period::On(1), Period::Off(10), Period::On(1)]
+/// );
+/// ```
+///
+/// A carrier straight after a character extends its last mark:
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{Period, Recorder};
+/// use megamorse::{Coalesce, MorsePlayer};
+///
+/// let mut player = MorsePlayer::new(Coalesce::new(Recorder::new()));
+///
+/// player.play_str("T").unwrap();
+/// player.play_carrier(3).unwrap();
+/// player.decoder_mut().flush().unwrap();
+///
+/// assert_eq!(player.decoder().decoder().timeline(), [Period::On(6)]);
+/// ```
+#[derive(Debug)]
+pub struct Coalesce<D> {
+    decoder: D,
+    /// The period that has not been passed on yet: its state, and its length
+    /// in time units.
+    pending: Option<(bool, usize)>,
+    /// The code of the pending mark, while it is a single dot or dash.
+    code: Option<MorseCode>,
+}
+
+impl<D: MorseDecoder> Coalesce<D> {
+    /// Wrap `decoder`.
+    pub fn new(decoder: D) -> Self {
+        Coalesce {
+            decoder,
+            pending: None,
+            code: None,
+        }
+    }
+
+    /// Pass the period that is held back on to the wrapped decoder.
+    pub fn flush(&mut self) -> Result<(), D::Error> {
+        match (self.pending.take(), self.code.take()) {
+            (Some((true, _)), Some(code)) => self.decoder.on_code(code),
+            (Some((true, timeunits)), None) => self.decoder.on(timeunits),
+            (Some((false, timeunits)), _) => self.decoder.off(timeunits),
+            (None, _) => Ok(()),
+        }
+    }
+
+    /// Returns a reference to the wrapped decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Consumes the wrapper, returning the wrapped decoder. The period that
+    /// is held back is lost, unless [Coalesce::flush] is called first.
+    pub fn into_decoder(self) -> D {
+        self.decoder
+    }
+
+    fn push(&mut self, on: bool, timeunits: usize) -> Result<(), D::Error> {
+        if timeunits == 0 {
+            return Ok(());
+        }
+
+        match &mut self.pending {
+            Some((state, pending)) if *state == on => {
+                *pending = pending.saturating_add(timeunits);
+                self.code = None;
+                Ok(())
+            }
+            _ => {
+                self.flush()?;
+                self.pending = Some((on, timeunits));
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<D: MorseDecoder> MorseDecoder for Coalesce<D> {
+    type Error = D::Error;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.push(true, timeunits)
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        let extends = matches!(self.pending, Some((true, _)));
+
+        self.push(true, code.timeunits())?;
+
+        if !extends {
+            self.code = Some(code);
+        }
+
+        Ok(())
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.push(false, timeunits)
+    }
+}
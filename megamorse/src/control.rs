@@ -0,0 +1,151 @@
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+
+/// A set of flags that can be used to abort or pause a running playback
+/// from outside the [MorsePlayer](crate::MorsePlayer), for example from an
+/// interrupt handler or from another thread.
+///
+/// The player checks for an abort request before every element it sends to
+/// the decoder, and for a pause request before every character. Pausing thus
+/// never cuts a character in half, and the returned [PlaybackCursor] can be
+/// used to continue at the exact character where playback stopped.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{assert_notation, Recorder};
+/// use megamorse::{MorsePlayer, Playback, PlaybackControl};
+///
+/// let control = PlaybackControl::new();
+/// let mut player = MorsePlayer::new(Recorder::new());
+///
+/// // Usually set by an interrupt handler or another thread.
+/// control.pause();
+///
+/// let Playback::Paused(cursor) = player.play_str_with("CQ CQ", &control).unwrap() else {
+///     panic!("playback should have been paused");
+/// };
+///
+/// control.resume();
+///
+/// assert_eq!(
+///     player.resume_str("CQ CQ", cursor, &control).unwrap(),
+///     Playback::Finished
+/// );
+/// assert_notation(player.decoder().timeline(), "-.-. --.- / -.-. --.-");
+/// ```
+#[derive(Debug, Default)]
+pub struct PlaybackControl {
+    abort: AtomicBool,
+    pause: AtomicBool,
+}
+
+impl PlaybackControl {
+    /// Create a new set of playback flags, with neither an abort nor a pause
+    /// requested.
+    pub const fn new() -> Self {
+        PlaybackControl {
+            abort: AtomicBool::new(false),
+            pause: AtomicBool::new(false),
+        }
+    }
+
+    /// Request the playback to be aborted at the next element boundary.
+    /// The player will then return [MorsePlayerError::Aborted](crate::MorsePlayerError::Aborted).
+    ///
+    /// The request stays active until [PlaybackControl::reset] is called.
+    pub fn abort(&self) {
+        self.abort.store(true, Ordering::Release);
+    }
+
+    /// Request the playback to be paused at the next character boundary.
+    ///
+    /// The request stays active until [PlaybackControl::resume] or
+    /// [PlaybackControl::reset] is called.
+    pub fn pause(&self) {
+        self.pause.store(true, Ordering::Release);
+    }
+
+    /// Withdraw a pause request, so that a paused playback can be continued.
+    pub fn resume(&self) {
+        self.pause.store(false, Ordering::Release);
+    }
+
+    /// Withdraw both the abort and the pause request.
+    pub fn reset(&self) {
+        self.abort.store(false, Ordering::Release);
+        self.pause.store(false, Ordering::Release);
+    }
+
+    /// Returns true if an abort has been requested.
+    pub fn is_aborted(&self) -> bool {
+        self.abort.load(Ordering::Acquire)
+    }
+
+    /// Returns true if a pause has been requested.
+    pub fn is_paused(&self) -> bool {
+        self.pause.load(Ordering::Acquire)
+    }
+}
+
+/// The position within a string that playback has reached.
+///
+/// Returned by the [MorsePlayer](crate::MorsePlayer) when playback is paused or
+/// fails, and can be passed back to [MorsePlayer::resume_str](crate::MorsePlayer::resume_str)
+/// to continue playing the same string from that position.
+///
+/// A cursor is a plain pair of numbers, so it can be stored in non-volatile
+/// memory to continue a message after a power loss. Use [PlaybackCursor::new]
+/// and the getters for a raw representation, or enable the `serde` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaybackCursor {
+    byte_offset: usize,
+    char_index: usize,
+}
+
+impl PlaybackCursor {
+    /// A cursor pointing at the start of a string.
+    pub const START: PlaybackCursor = PlaybackCursor {
+        byte_offset: 0,
+        char_index: 0,
+    };
+
+    /// Create a cursor from a previously stored byte offset and character index.
+    ///
+    /// The byte offset determines where playback continues. The character
+    /// index is only reported back, and should match the byte offset.
+    pub const fn new(byte_offset: usize, char_index: usize) -> Self {
+        PlaybackCursor {
+            byte_offset,
+            char_index,
+        }
+    }
+
+    /// The byte offset of the next character to be played.
+    pub const fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// The index of the next character to be played, counted in [char]s.
+    pub const fn char_index(&self) -> usize {
+        self.char_index
+    }
+
+    pub(crate) fn advance(&mut self, c: char) {
+        self.byte_offset += c.len_utf8();
+        self.char_index += 1;
+    }
+}
+
+/// The outcome of a controlled playback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Playback {
+    /// The whole string has been played.
+    Finished,
+
+    /// Playback was paused through a [PlaybackControl] before the given
+    /// character.
+    Paused(PlaybackCursor),
+}
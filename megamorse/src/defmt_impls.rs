@@ -0,0 +1,25 @@
+use defmt::{Format, Formatter};
+
+use crate::MorsePlayerError;
+
+/// Formatted like the [Display](core::fmt::Display) implementation.
+impl<DecoderError: Format> Format for MorsePlayerError<DecoderError> {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            MorsePlayerError::InvalidCharacter {
+                character,
+                byte_offset,
+            } => defmt::write!(
+                f,
+                "character '{=char}' at byte offset {=usize} has no Morse code representation",
+                character,
+                byte_offset
+            ),
+            MorsePlayerError::DecoderError(error) => defmt::write!(f, "decoder error: {}", error),
+            MorsePlayerError::Aborted => defmt::write!(f, "playback was aborted"),
+            MorsePlayerError::InvalidCursor => {
+                defmt::write!(f, "playback cursor does not point at a character boundary")
+            }
+        }
+    }
+}
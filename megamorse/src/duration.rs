@@ -0,0 +1,174 @@
+use core::convert::Infallible;
+use core::time::Duration;
+
+use crate::timing::Keying;
+use crate::InvalidCharacterPolicy;
+use crate::MorseCode;
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::MorseWord;
+use crate::Timing;
+
+/// A decoder that only measures what is played.
+struct Counter {
+    keying: Keying,
+    timeunits: usize,
+    micros: u64,
+}
+
+impl Counter {
+    fn new(timing: Timing) -> Self {
+        Counter {
+            keying: Keying::new(timing),
+            timeunits: 0,
+            micros: 0,
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        Duration::from_micros(self.micros)
+    }
+}
+
+impl MorseDecoder for Counter {
+    type Error = Infallible;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.timeunits = self.timeunits.saturating_add(timeunits);
+        self.micros = self.micros.saturating_add(self.keying.mark(timeunits));
+        Ok(())
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        self.timeunits = self.timeunits.saturating_add(code.timeunits());
+        self.micros = self.micros.saturating_add(self.keying.code(code));
+        Ok(())
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.timeunits = self.timeunits.saturating_add(timeunits);
+        self.micros = self.micros.saturating_add(self.keying.space(timeunits));
+        Ok(())
+    }
+}
+
+fn count_str(
+    source: &str,
+    timing: Timing,
+    policy: InvalidCharacterPolicy,
+) -> Result<Counter, MorsePlayerError<Infallible>> {
+    let mut player = MorsePlayer::new(Counter::new(timing)).with_invalid_character_policy(policy);
+
+    player.play_str(source)?;
+
+    Ok(player.into_decoder())
+}
+
+fn count_words(words: &[MorseWord], timing: Timing) -> Counter {
+    let mut player = MorsePlayer::new(Counter::new(timing));
+
+    for (index, word) in words.iter().enumerate() {
+        // Counting cannot fail, as the decoder is infallible
+        if index != 0 {
+            let _ = player.decoder_mut().off(3);
+        }
+
+        let _ = player.play_word(*word);
+    }
+
+    player.into_decoder()
+}
+
+/// The amount of time units [MorsePlayer::play_str] takes to play `source`,
+/// for a player with the given [InvalidCharacterPolicy].
+///
+/// Like [MorsePlayer::play_str], this does not include a pause after the last
+/// character. With [InvalidCharacterPolicy::Reject], returns
+/// [MorsePlayerError::InvalidCharacter] for the first character that has no
+/// Morse code representation.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{str_units, InvalidCharacterPolicy, Prosign, PARIS_UNITS};
+///
+/// let policy = InvalidCharacterPolicy::Reject;
+///
+/// // The standard word includes the word pause after it
+/// assert_eq!(str_units("PARIS", policy).unwrap() + 7, PARIS_UNITS);
+/// assert_eq!(str_units("SOS SOS", policy).unwrap(), 27 + 7 + 27);
+/// assert!(str_units("SOS!", policy).is_err());
+///
+/// // Count what a player that skips or substitutes characters plays
+/// assert_eq!(str_units("SOS!", InvalidCharacterPolicy::Skip).unwrap(), 27);
+/// assert_eq!(
+///     str_units("E!", InvalidCharacterPolicy::Substitute(Prosign::QUESTION_MARK)).unwrap(),
+///     1 + 3 + 15
+/// );
+/// ```
+pub fn str_units(
+    source: &str,
+    policy: InvalidCharacterPolicy,
+) -> Result<usize, MorsePlayerError<Infallible>> {
+    Ok(count_str(source, Timing::new(0), policy)?.timeunits)
+}
+
+/// The time [MorsePlayer::play_str] takes to play `source` through a [Keyer](crate::Keyer)
+/// with the given timing, for a player with the given [InvalidCharacterPolicy].
+///
+/// Like [MorsePlayer::play_str], this does not include a pause after the last
+/// character. With [InvalidCharacterPolicy::Reject], returns
+/// [MorsePlayerError::InvalidCharacter] for the first character that has no
+/// Morse code representation.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use megamorse::{str_duration, InvalidCharacterPolicy, Timing};
+///
+/// let timing = Timing::from_wpm(20);
+///
+/// assert_eq!(
+///     str_duration("PARIS", timing, InvalidCharacterPolicy::Reject).unwrap(),
+///     Duration::from_millis(43 * 60)
+/// );
+/// ```
+pub fn str_duration(
+    source: &str,
+    timing: Timing,
+    policy: InvalidCharacterPolicy,
+) -> Result<Duration, MorsePlayerError<Infallible>> {
+    Ok(count_str(source, timing, policy)?.duration())
+}
+
+/// The amount of time units it takes to play `words` as the characters of a
+/// single word, separated by the usual pause of 3 time units.
+///
+/// This matches what [MorsePlayer::play_str] plays for the characters the
+/// words represent.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{morse, str_units, words_units, InvalidCharacterPolicy};
+///
+/// assert_eq!(
+///     words_units(&morse!(... ___ ...)),
+///     str_units("SOS", InvalidCharacterPolicy::Reject).unwrap()
+/// );
+/// ```
+pub fn words_units(words: &[MorseWord]) -> usize {
+    count_words(words, Timing::new(0)).timeunits
+}
+
+/// The time it takes to play `words` as the characters of a single word
+/// through a [Keyer](crate::Keyer) with the given timing.
+///
+/// This matches what [MorsePlayer::play_str] plays for the characters the
+/// words represent.
+pub fn words_duration(words: &[MorseWord], timing: Timing) -> Duration {
+    count_words(words, timing).duration()
+}
@@ -41,8 +41,79 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::fmt;
+
+mod adapters;
+mod ardf;
+mod beacon;
+mod buffer;
+mod clock;
+mod coalesce;
+mod control;
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+mod duration;
+mod message;
+mod notation;
+mod policy;
+mod ptt;
+mod queue;
+mod schedule;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod session;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tick;
+mod timed;
+mod timing;
+#[cfg(feature = "ufmt")]
+mod ufmt_impls;
+mod writer;
+
+#[doc(inline)]
+pub use adapters::*;
+#[doc(inline)]
+pub use ardf::*;
+#[doc(inline)]
+pub use beacon::*;
+#[doc(inline)]
+pub use buffer::*;
+#[doc(inline)]
+pub use clock::*;
+#[doc(inline)]
+pub use coalesce::*;
+#[doc(inline)]
+pub use control::*;
+#[doc(inline)]
+pub use duration::*;
 #[doc(inline)]
 pub use megamorse_core::*;
+#[doc(inline)]
+pub use message::*;
+#[doc(inline)]
+pub use notation::*;
+#[doc(inline)]
+pub use policy::*;
+#[doc(inline)]
+pub use ptt::*;
+#[doc(inline)]
+pub use queue::*;
+#[doc(inline)]
+pub use schedule::*;
+#[doc(inline)]
+pub use session::*;
+#[doc(inline)]
+pub use tick::*;
+#[doc(inline)]
+pub use timed::*;
+#[doc(inline)]
+pub use timing::*;
+#[doc(inline)]
+pub use writer::*;
 
 /// This macro is used to generate a static array of [MorseWord] structs from a
 /// literal whitespace-delimited sequence of dots and dashes.
@@ -53,10 +124,13 @@ pub use megamorse_core::*;
 /// as each alphanumeric character is mapped to a sequence of up to five Morse
 /// code characters.
 ///
+/// To translate text rather than dot-dash notation at compile time, use
+/// [encode_str].
+///
 /// # Examples
 ///
 /// ```
-/// use megamorse::morse;
+/// use megamorse::{morse, MorseWord};
 ///
 /// // Maps to the Morse words representing "S" "O" "S"
 /// const SOS: [MorseWord; 3] = morse!(... ___ ...);
@@ -77,6 +151,10 @@ pub use megamorse_proc_macro::morse;
 /// will abort the playback of the Morse code sequence,
 /// and will have the [MorsePlayer] return the error
 /// to its caller wrapped in a [MorsePlayerError::DecoderError].
+///
+/// Decoders that work with real durations rather than
+/// time units can implement [DurationDecoder] instead,
+/// and be wrapped in a [Timed].
 pub trait MorseDecoder {
     /// The error type that the decoder can return.
     type Error;
@@ -91,6 +169,14 @@ pub trait MorseDecoder {
     /// # Examples
     ///
     /// ```
+    /// # use megamorse::MorseDecoder;
+    /// # struct Led;
+    /// # impl Led { fn on(&self) {} fn off(&self) {} }
+    /// # fn get_led() -> Led { Led }
+    /// # fn sleep(_ms: usize) {}
+    /// # struct LedDecoder;
+    /// # impl MorseDecoder for LedDecoder {
+    /// # type Error = ();
     /// fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
     ///     let led = get_led();
     ///     // Turn the output on for the specified number of time units.
@@ -102,8 +188,31 @@ pub trait MorseDecoder {
     ///     led.off();
     ///     Ok(())
     /// }
+    /// # fn off(&mut self, _timeunits: usize) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// ```
     fn on(&mut self, timeunits: usize) -> Result<(), Self::Error>;
 
+    /// Set the decoder output to "on" for a single dot or dash of a
+    /// character.
+    ///
+    /// The [MorsePlayer] calls this instead of [MorseDecoder::on] for the
+    /// elements of characters, so that decoders can tell dots and dashes
+    /// apart from other marks, such as a carrier. This is what a
+    /// [Keyer] needs to apply its dash ratio to dashes only.
+    ///
+    /// The default implementation calls [MorseDecoder::on] with the standard
+    /// length of the code, so most decoders do not need to implement it.
+    /// Decoders that wrap another decoder without changing its time units
+    /// should pass it on.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The dot or dash to play.
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        self.on(code.timeunits())
+    }
+
     /// Pause the decoder output for a given number of time units.
     ///
     /// The function should ensure that it does not return until the
@@ -120,6 +229,12 @@ pub trait MorseDecoder {
     /// # Examples
     ///
     /// ```
+    /// # use megamorse::MorseDecoder;
+    /// # fn sleep(_ms: usize) {}
+    /// # struct LedDecoder;
+    /// # impl MorseDecoder for LedDecoder {
+    /// # type Error = ();
+    /// # fn on(&mut self, _timeunits: usize) -> Result<(), Self::Error> { Ok(()) }
     /// fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
     ///     // Sleep for the specified number of time units, and
     ///     // return control to the player once the sleep has completed.
@@ -127,6 +242,7 @@ pub trait MorseDecoder {
     ///     sleep(timeunits * timeunit_ms);
     ///     Ok(())
     /// }
+    /// # }
     /// ````
     ///
     fn off(&mut self, timeunits: usize) -> Result<(), Self::Error>;
@@ -141,18 +257,34 @@ pub trait MorseDecoder {
 /// actually driving the output, such as turning on a light or a buzzer.
 ///
 /// [^valid_chars]: Valid characters are alphanumeric characters [a-z] and [A-Z], and the characters [0-9].
+#[derive(Debug)]
 pub struct MorsePlayer<T: MorseDecoder> {
     decoder: T,
+    invalid_characters: InvalidCharacterPolicy,
 }
 
 /// Errors that can occur during Morse code playback.
 #[derive(Debug)]
 pub enum MorsePlayerError<DecoderError> {
-    /// An invalid character was encountered in the input.
-    InvalidCharacter,
+    /// An invalid character was encountered in the input, and the player's
+    /// [InvalidCharacterPolicy] is to reject it.
+    InvalidCharacter {
+        /// The character without a Morse code representation.
+        character: char,
+
+        /// The byte offset of the character within the played string.
+        byte_offset: usize,
+    },
 
     /// An error occurred in the decoder.
     DecoderError(DecoderError),
+
+    /// Playback was aborted through a [PlaybackControl].
+    Aborted,
+
+    /// A [PlaybackCursor] was passed that does not point at a character
+    /// boundary of the string being played.
+    InvalidCursor,
 }
 
 impl<DecoderError> From<DecoderError> for MorsePlayerError<DecoderError> {
@@ -161,61 +293,636 @@ impl<DecoderError> From<DecoderError> for MorsePlayerError<DecoderError> {
     }
 }
 
+impl<DecoderError: fmt::Display> fmt::Display for MorsePlayerError<DecoderError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MorsePlayerError::InvalidCharacter {
+                character,
+                byte_offset,
+            } => write!(
+                f,
+                "character {:?} at byte offset {} has no Morse code representation",
+                character, byte_offset
+            ),
+            MorsePlayerError::DecoderError(error) => write!(f, "decoder error: {}", error),
+            MorsePlayerError::Aborted => f.write_str("playback was aborted"),
+            MorsePlayerError::InvalidCursor => {
+                f.write_str("playback cursor does not point at a character boundary")
+            }
+        }
+    }
+}
+
+impl<DecoderError> core::error::Error for MorsePlayerError<DecoderError>
+where
+    DecoderError: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MorsePlayerError::DecoderError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// An error that occurred while playing a string, together with the position
+/// that playback had reached when the error occurred.
+///
+/// The cursor points at the character that was being played, so passing it to
+/// [MorsePlayer::resume_str] retries that character and continues from there.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{MorseDecoder, MorsePlayer, MorsePlayerError, Playback, PlaybackControl};
+///
+/// // A decoder that fails once after a given amount of elements, like a
+/// // transmitter that browns out.
+/// struct FlakyDecoder {
+///     elements_left: usize,
+/// }
+///
+/// impl MorseDecoder for FlakyDecoder {
+///     type Error = ();
+///
+///     fn on(&mut self, _timeunits: usize) -> Result<(), Self::Error> {
+///         if self.elements_left == 0 {
+///             self.elements_left = usize::MAX;
+///             return Err(());
+///         }
+///
+///         self.elements_left -= 1;
+///         Ok(())
+///     }
+///
+///     fn off(&mut self, _timeunits: usize) -> Result<(), Self::Error> {
+///         Ok(())
+///     }
+/// }
+///
+/// let control = PlaybackControl::new();
+/// let mut player = MorsePlayer::new(FlakyDecoder { elements_left: 4 });
+///
+/// // "S" has three elements, so the second element of "O" fails
+/// let err = player.play_str_with("SOS", &control).unwrap_err();
+///
+/// assert!(matches!(err.error(), MorsePlayerError::DecoderError(())));
+/// assert_eq!(err.cursor().byte_offset(), 1);
+/// assert_eq!(err.cursor().char_index(), 1);
+///
+/// // Retry from the "O"
+/// assert_eq!(
+///     player.resume_str("SOS", err.cursor(), &control).unwrap(),
+///     Playback::Finished
+/// );
+/// ```
+#[derive(Debug)]
+pub struct PlaybackError<DecoderError> {
+    error: MorsePlayerError<DecoderError>,
+    cursor: PlaybackCursor,
+}
+
+impl<DecoderError> PlaybackError<DecoderError> {
+    /// The error that stopped playback.
+    pub fn error(&self) -> &MorsePlayerError<DecoderError> {
+        &self.error
+    }
+
+    /// The position of the character that was being played when the error
+    /// occurred.
+    pub fn cursor(&self) -> PlaybackCursor {
+        self.cursor
+    }
+
+    /// Returns the error that stopped playback, discarding the position.
+    pub fn into_error(self) -> MorsePlayerError<DecoderError> {
+        self.error
+    }
+}
+
+impl<DecoderError> From<PlaybackError<DecoderError>> for MorsePlayerError<DecoderError> {
+    fn from(error: PlaybackError<DecoderError>) -> Self {
+        error.error
+    }
+}
+
+impl<DecoderError: fmt::Display> fmt::Display for PlaybackError<DecoderError> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (stopped at character {})",
+            self.error,
+            self.cursor.char_index()
+        )
+    }
+}
+
+impl<DecoderError> core::error::Error for PlaybackError<DecoderError>
+where
+    DecoderError: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Tracks which pause has to be played before the next character of a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Spacing {
+    /// Whether a character has been played, so the next one needs a pause.
+    started: bool,
+
+    /// Whether whitespace was encountered since the previous character.
+    word: bool,
+
+    /// The part of the pause that has already been played ahead of time.
+    paused: usize,
+}
+
+impl Spacing {
+    const START: Spacing = Spacing {
+        started: false,
+        word: false,
+        paused: 0,
+    };
+
+    fn whitespace(&mut self) {
+        self.word = self.started;
+    }
+
+    /// The pause that still has to be played before the next character.
+    fn pause(self) -> Option<usize> {
+        let full: usize = match (self.started, self.word) {
+            (false, _) => 0,
+            (true, false) => 3,
+            (true, true) => 7,
+        };
+
+        let remaining = full.saturating_sub(self.paused);
+
+        (remaining > 0).then_some(remaining)
+    }
+
+    fn paused(&mut self, timeunits: usize) {
+        self.paused = self.paused.saturating_add(timeunits);
+    }
+
+    fn played(&mut self) {
+        *self = Spacing {
+            started: true,
+            word: false,
+            paused: 0,
+        };
+    }
+}
+
 impl<T: MorseDecoder> MorsePlayer<T> {
     /// Create a new Morse player with a given decoder.
     pub fn new(decoder: T) -> Self {
-        MorsePlayer { decoder }
+        MorsePlayer {
+            decoder,
+            invalid_characters: InvalidCharacterPolicy::default(),
+        }
     }
 
-    fn play_str_word(&mut self, word: &str) -> Result<(), MorsePlayerError<T::Error>> {
-        for (index, c) in word.chars().enumerate() {
-            if index != 0 {
-                self.decoder.off(3)?;
+    /// Returns a reference to the decoder used by the player.
+    pub fn decoder(&self) -> &T {
+        &self.decoder
+    }
+
+    /// Returns a mutable reference to the decoder used by the player.
+    pub fn decoder_mut(&mut self) -> &mut T {
+        &mut self.decoder
+    }
+
+    /// Consumes the player, returning the decoder it used.
+    pub fn into_decoder(self) -> T {
+        self.decoder
+    }
+
+    /// Returns a [MorseWriter] that plays everything written to it, so that
+    /// the player can be used with [write!].
+    ///
+    /// The pauses between characters and words are tracked for as long as the
+    /// writer lives, so the first character written is not preceded by a
+    /// pause.
+    pub fn writer(&mut self) -> MorseWriter<'_, T> {
+        MorseWriter::new(self.session())
+    }
+
+    /// Start a [MorseSession], which plays characters one at a time as they
+    /// are pushed, while keeping track of the pauses between them.
+    pub fn session(&mut self) -> MorseSession<'_, T> {
+        MorseSession::new(self, None)
+    }
+
+    /// Start a [MorseSession] that can be aborted through a
+    /// [PlaybackControl].
+    ///
+    /// An abort request is honoured before the next element, and makes the
+    /// session return [MorsePlayerError::Aborted], including from
+    /// [MorseSession::flush]. A session has no text to continue later, so
+    /// pause requests are left to the code pushing the characters, which can
+    /// check [PlaybackControl::is_paused] itself.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::{assert_notation, Recorder};
+    /// use megamorse::{MorsePlayer, MorsePlayerError, PlaybackControl};
+    ///
+    /// let control = PlaybackControl::new();
+    /// let mut player = MorsePlayer::new(Recorder::new());
+    /// let mut session = player.session_with(&control);
+    ///
+    /// session.push_str("CQ ").unwrap();
+    /// control.abort();
+    ///
+    /// // Not even the pending word pause is played anymore
+    /// assert!(matches!(session.flush(), Err(MorsePlayerError::Aborted)));
+    /// assert_notation(player.decoder().timeline(), "-.-. --.-");
+    /// ```
+    pub fn session_with<'a>(&'a mut self, control: &'a PlaybackControl) -> MorseSession<'a, T> {
+        MorseSession::new(self, Some(control))
+    }
+
+    /// Set what the player does with characters that have no Morse code
+    /// representation when playing a string.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::{assert_notation, Recorder};
+    /// use megamorse::{InvalidCharacterPolicy, MorsePlayer, Prosign};
+    ///
+    /// let mut player = MorsePlayer::new(Recorder::new())
+    ///     .with_invalid_character_policy(InvalidCharacterPolicy::Substitute(Prosign::QUESTION_MARK));
+    ///
+    /// // The "," is played as a question mark
+    /// player.play_str("Hi, Bob").unwrap();
+    /// assert_notation(player.decoder().timeline(), ".... .. ..--.. / -... --- -...");
+    /// ```
+    pub fn with_invalid_character_policy(mut self, policy: InvalidCharacterPolicy) -> Self {
+        self.invalid_characters = policy;
+        self
+    }
+
+    fn on(
+        &mut self,
+        timeunits: usize,
+        control: Option<&PlaybackControl>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        if control.is_some_and(PlaybackControl::is_aborted) {
+            return Err(MorsePlayerError::Aborted);
+        }
+
+        Ok(self.decoder.on(timeunits)?)
+    }
+
+    fn on_code(
+        &mut self,
+        code: MorseCode,
+        control: Option<&PlaybackControl>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        if control.is_some_and(PlaybackControl::is_aborted) {
+            return Err(MorsePlayerError::Aborted);
+        }
+
+        Ok(self.decoder.on_code(code)?)
+    }
+
+    fn off(
+        &mut self,
+        timeunits: usize,
+        control: Option<&PlaybackControl>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        if control.is_some_and(PlaybackControl::is_aborted) {
+            return Err(MorsePlayerError::Aborted);
+        }
+
+        Ok(self.decoder.off(timeunits)?)
+    }
+
+    /// Plays `source`, starting at `cursor`. Words are separated by a pause
+    /// of 7 time units, and characters within a word by a pause of 3 time
+    /// units. Nothing is played before the first character, so resuming
+    /// halfway through a string does not add a leading pause.
+    fn play_from(
+        &mut self,
+        source: &str,
+        mut cursor: PlaybackCursor,
+        control: Option<&PlaybackControl>,
+    ) -> Result<Playback, PlaybackError<T::Error>> {
+        let Some(remaining) = source.get(cursor.byte_offset()..) else {
+            return Err(PlaybackError {
+                error: MorsePlayerError::InvalidCursor,
+                cursor,
+            });
+        };
+
+        let mut spacing = Spacing::START;
+
+        for c in remaining.chars() {
+            if !c.is_whitespace() && control.is_some_and(PlaybackControl::is_paused) {
+                return Ok(Playback::Paused(cursor));
+            }
+
+            self.play_char(c, cursor, &mut spacing, control)
+                .map_err(|error| PlaybackError { error, cursor })?;
+
+            cursor.advance(c);
+        }
+
+        Ok(Playback::Finished)
+    }
+
+    /// Plays a single character of a string, preceded by the pause that
+    /// `spacing` asks for. Whitespace is not played, but makes the next
+    /// character start a new word.
+    fn play_char(
+        &mut self,
+        c: char,
+        cursor: PlaybackCursor,
+        spacing: &mut Spacing,
+        control: Option<&PlaybackControl>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        if c.is_whitespace() {
+            spacing.whitespace();
+            return Ok(());
+        }
+
+        let prosign = match (MorseWord::try_from(c), self.invalid_characters) {
+            (Ok(mword), _) => Prosign::from(mword),
+            (Err(_), InvalidCharacterPolicy::Skip) => return Ok(()),
+            (Err(_), InvalidCharacterPolicy::Substitute(prosign)) => prosign,
+            (Err(_), InvalidCharacterPolicy::Reject) => {
+                return Err(MorsePlayerError::InvalidCharacter {
+                    character: c,
+                    byte_offset: cursor.byte_offset(),
+                })
             }
+        };
 
-            let mword = MorseWord::try_from(c).map_err(|_| MorsePlayerError::InvalidCharacter)?;
+        self.play_spaced(prosign, spacing, control)
+    }
 
-            self.play_word(mword)?;
+    /// Plays a character, preceded by the pause that `spacing` asks for.
+    fn play_spaced(
+        &mut self,
+        prosign: Prosign,
+        spacing: &mut Spacing,
+        control: Option<&PlaybackControl>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        if let Some(pause) = spacing.pause() {
+            self.off(pause, control)?;
         }
 
+        self.play_prosign_with(prosign, control)?;
+
+        spacing.played();
+
         Ok(())
     }
 
     /// Play a Morse code sequence represented by a string.
     /// The string should contain characters that have a valid Morse code sequence associated with them.
     ///
+    /// Use [MorsePlayer::play_str_with] to also learn where playback stopped,
+    /// as its [PlaybackError] carries the position of the failing character.
+    ///
     /// # Arguments
     ///
     /// * `source` - The string containing the Morse code sequence to play.
     ///
     /// # Examples
     ///
-    /// ```
-    /// let player = MorsePlayer::new(MyDecoder::new());
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// # use megamorse::{MorsePlayer, MorsePlayerError};
+    /// # use megamorse::testing::{assert_notation, Recorder};
+    /// let mut player = MorsePlayer::new(Recorder::new());
     /// // Play the Morse code sequence for "SOS".
     /// player.play_str("SOS").unwrap();
+    /// assert_notation(player.decoder().timeline(), "... --- ...");
     ///
     /// // Play the Morse code sequence for "Hello world". Spaces are automatically
     /// // encoded as pauses between words.
     /// player.play_str("Hello world").unwrap();
     ///
     /// // Will error, as both "," and "!" are invalid characters and have no Morse code representation.
-    /// player.play_str("Hello, world!").unwrap();
+    /// // Use megamorse::validate to find all of them before playing anything.
+    /// let err = player.play_str("Hello, world!").unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     err,
+    ///     MorsePlayerError::InvalidCharacter {
+    ///         character: ',',
+    ///         byte_offset: 5
+    ///     }
+    /// ));
     /// ````
     pub fn play_str(&mut self, source: &str) -> Result<(), MorsePlayerError<T::Error>> {
-        let words = source.split_whitespace();
+        self.play_from(source, PlaybackCursor::START, None)
+            .map_err(PlaybackError::into_error)?;
+
+        Ok(())
+    }
+
+    /// Play a Morse code sequence represented by a string, while allowing the
+    /// playback to be aborted or paused through a [PlaybackControl].
+    ///
+    /// An abort request is honoured before the next element, and makes this
+    /// method return [MorsePlayerError::Aborted], with the position of the
+    /// character that was interrupted. A pause request is honoured
+    /// before the next character, and makes this method return
+    /// [Playback::Paused] with a cursor that can be passed to
+    /// [MorsePlayer::resume_str] to continue at that character.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The string containing the Morse code sequence to play.
+    /// * `control` - The flags that are checked during playback.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::Recorder;
+    /// use megamorse::{MorsePlayer, MorsePlayerError, PlaybackControl};
+    ///
+    /// let control = PlaybackControl::new();
+    /// let mut player = MorsePlayer::new(Recorder::new());
+    ///
+    /// control.abort();
+    ///
+    /// let err = player.play_str_with("VVV DE N0CALL", &control).unwrap_err();
+    ///
+    /// assert!(matches!(err.error(), MorsePlayerError::Aborted));
+    /// assert!(player.decoder().timeline().is_empty());
+    /// ```
+    pub fn play_str_with(
+        &mut self,
+        source: &str,
+        control: &PlaybackControl,
+    ) -> Result<Playback, PlaybackError<T::Error>> {
+        self.play_from(source, PlaybackCursor::START, Some(control))
+    }
+
+    /// Continue playback of `source` at the character `cursor` points to.
+    ///
+    /// The cursor should have been obtained from a paused playback or a
+    /// [PlaybackError] of the same string, possibly saved and restored across
+    /// a restart of the device. If `control` is still paused, this returns the
+    /// same cursor without playing anything.
+    ///
+    /// Returns [MorsePlayerError::InvalidCursor] if the cursor does not point
+    /// at a character boundary of `source`, which can only happen if it was
+    /// obtained for a different string.
+    pub fn resume_str(
+        &mut self,
+        source: &str,
+        cursor: PlaybackCursor,
+        control: &PlaybackControl,
+    ) -> Result<Playback, PlaybackError<T::Error>> {
+        self.play_from(source, cursor, Some(control))
+    }
+
+    fn play_word_with(
+        &mut self,
+        word: MorseWord,
+        control: Option<&PlaybackControl>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        let (seq_len, seq_padded) = word.to_sequence();
 
-        for (index, word) in words.enumerate() {
+        seq_padded
+            .into_iter()
+            .take(seq_len)
+            .try_for_each(|seq| match seq {
+                MorseSequence::Code(code) => self.on_code(code, control),
+                MorseSequence::Pause => self.off(1, control),
+            })
+    }
+
+    fn play_prosign_with(
+        &mut self,
+        prosign: Prosign,
+        control: Option<&PlaybackControl>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        for (index, word) in prosign.words().iter().enumerate() {
             if index != 0 {
-                self.decoder.off(7)?;
+                self.off(1, control)?;
             }
 
-            self.play_str_word(word)?;
+            self.play_word_with(*word, control)?;
         }
 
         Ok(())
     }
 
+    /// Play a [Prosign], running its words together without the usual pause
+    /// between characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `prosign` - The prosign to play.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::{assert_notation, Recorder};
+    /// use megamorse::{MorsePlayer, Prosign};
+    ///
+    /// let mut player = MorsePlayer::new(Recorder::new());
+    ///
+    /// // End of contact
+    /// player.play_prosign(Prosign::SK).unwrap();
+    /// assert_notation(player.decoder().timeline(), "...-.-");
+    /// ```
+    pub fn play_prosign(&mut self, prosign: Prosign) -> Result<(), MorsePlayerError<T::Error>> {
+        self.play_prosign_with(prosign, None)
+    }
+
+    /// Play a [MorseMessage], a `MorseMessageVec` or any other slice of
+    /// [MessageElement]s.
+    ///
+    /// Characters and prosigns are separated by a pause of 3 time units, or 7
+    /// time units after a [MessageElement::WordBreak]. A
+    /// [MessageElement::Gap] is played as is, and counts towards the pause
+    /// before the next character.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to play.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse::{MessageElement, MorseMessage, MorsePlayer, Prosign, UnitNotationWriter};
+    ///
+    /// let mut message = MorseMessage::<8>::from_text("E E").unwrap();
+    ///
+    /// message.push(MessageElement::Gap(5)).unwrap();
+    /// message.push(Prosign::AR.into()).unwrap();
+    ///
+    /// let mut player = MorsePlayer::new(UnitNotationWriter::new(String::new()));
+    ///
+    /// player.play_message(&message).unwrap();
+    ///
+    /// assert_eq!(player.decoder().writer(), "=.......=.....=.===.=.===.=");
+    /// ```
+    pub fn play_message(
+        &mut self,
+        message: impl AsRef<[MessageElement]>,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        let mut spacing = Spacing::START;
+
+        for element in message.as_ref() {
+            match *element {
+                MessageElement::Character(word) => {
+                    self.play_spaced(Prosign::from(word), &mut spacing, None)?
+                }
+                MessageElement::Prosign(prosign) => {
+                    self.play_spaced(prosign, &mut spacing, None)?
+                }
+                MessageElement::WordBreak => spacing.whitespace(),
+                MessageElement::Gap(0) => {}
+                MessageElement::Gap(timeunits) => {
+                    self.off(timeunits, None)?;
+                    spacing.paused(timeunits);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Key the output continuously for the given amount of time units, for
+    /// example to send a long tuning or direction-finding carrier.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeunits` - The number of time units to keep the output on for.
+    pub fn play_carrier(&mut self, timeunits: usize) -> Result<(), MorsePlayerError<T::Error>> {
+        self.on(timeunits, None)
+    }
+
+    /// Key a continuous carrier, unless an abort has been requested through
+    /// `control`, in which case [MorsePlayerError::Aborted] is returned and
+    /// nothing is played.
+    ///
+    /// The carrier is a single element, so it is not cut short by a later
+    /// abort request.
+    pub fn play_carrier_with(
+        &mut self,
+        timeunits: usize,
+        control: &PlaybackControl,
+    ) -> Result<(), MorsePlayerError<T::Error>> {
+        self.on(timeunits, Some(control))
+    }
+
     /// Play a Morse code sequence represented by a [MorseWord] struct.
     /// Can be used to play Morse code sequences generated by the [morse] macro,
     /// but otherwise it is recommended to use the [MorsePlayer::play_str] method.
@@ -226,31 +933,28 @@ impl<T: MorseDecoder> MorsePlayer<T> {
     ///
     /// # Examples
     ///
-    /// ```
-    /// let player = MorsePlayer::new(MyDecoder::new());
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// # use megamorse::{morse, MorseDecoder, MorsePlayer};
+    /// # use megamorse::testing::{assert_notation, Recorder};
+    /// let mut player = MorsePlayer::new(Recorder::new());
     ///
     /// let sos = morse!(... ___ ...);
     ///
-    /// // Play the Morse code sequence for "SOS".
+    /// // Play the Morse code sequence for "SOS". Only the elements of a word
+    /// // are played, so the pause between the characters is up to the caller.
+    ///
+    /// for (index, word) in sos.into_iter().enumerate() {
+    ///    if index != 0 {
+    ///        player.decoder_mut().off(3).unwrap();
+    ///    }
     ///
-    /// for word in sos.into_iter() {
     ///    player.play_word(word).unwrap();
     /// }
+    ///
+    /// assert_notation(player.decoder().timeline(), "... --- ...");
     /// ````
     pub fn play_word(&mut self, word: MorseWord) -> Result<(), MorsePlayerError<T::Error>> {
-        let (seq_len, seq_padded) = word.to_sequence();
-
-        seq_padded
-            .into_iter()
-            .take(seq_len)
-            .try_for_each(|seq| match seq {
-                MorseSequence::Code(code) => match code {
-                    MorseCode::Dot => self.decoder.on(1),
-                    MorseCode::Dash => self.decoder.on(3),
-                },
-                MorseSequence::Pause => self.decoder.off(1),
-            })?;
-
-        Ok(())
+        self.play_word_with(word, None)
     }
 }
@@ -0,0 +1,547 @@
+use core::fmt;
+use core::slice;
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::parse_notation;
+use crate::MorseWord;
+use crate::NotationError;
+use crate::NotationItem;
+use crate::Prosign;
+
+/// A single element of a [MorseMessage] or `MorseMessageVec`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MessageElement {
+    /// A character, separated from the previous one by a pause of 3 time
+    /// units.
+    Character(MorseWord),
+
+    /// A prosign, played as a single character with its words run together.
+    Prosign(Prosign),
+
+    /// A break between words, which makes the pause before the next
+    /// character 7 time units long instead of 3.
+    WordBreak,
+
+    /// A pause of the given amount of time units. The pause counts towards the
+    /// pause before the next character, so a gap of 7 time units between two
+    /// characters sounds just like a word break.
+    Gap(usize),
+}
+
+impl From<MorseWord> for MessageElement {
+    fn from(word: MorseWord) -> Self {
+        MessageElement::Character(word)
+    }
+}
+
+impl From<Prosign> for MessageElement {
+    fn from(prosign: Prosign) -> Self {
+        MessageElement::Prosign(prosign)
+    }
+}
+
+/// Errors returned when building a [MorseMessage] or `MorseMessageVec`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageError {
+    /// The message has no room for another element.
+    Full,
+
+    /// Text contained a character without a Morse code representation.
+    InvalidCharacter {
+        /// The character without a Morse code representation.
+        character: char,
+
+        /// The byte offset of the character within the text.
+        byte_offset: usize,
+    },
+
+    /// Dot-dash notation could not be parsed.
+    Notation(NotationError),
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageError::Full => f.write_str("message is full"),
+            MessageError::InvalidCharacter {
+                character,
+                byte_offset,
+            } => write!(
+                f,
+                "character {:?} at byte offset {} has no Morse code representation",
+                character, byte_offset
+            ),
+            MessageError::Notation(error) => write!(f, "invalid notation: {}", error),
+        }
+    }
+}
+
+impl core::error::Error for MessageError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MessageError::Notation(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<NotationError> for MessageError {
+    fn from(error: NotationError) -> Self {
+        MessageError::Notation(error)
+    }
+}
+
+/// The storage behind both message types, so they are built the same way.
+pub(crate) trait Elements {
+    fn as_slice(&self) -> &[MessageElement];
+
+    fn push(&mut self, element: MessageElement) -> Result<(), MessageError>;
+
+    fn truncate(&mut self, len: usize);
+}
+
+/// Adds a word break, unless it would be the first element or follow another
+/// word break.
+fn push_word_break(message: &mut impl Elements) -> Result<(), MessageError> {
+    match message.as_slice().last() {
+        None | Some(MessageElement::WordBreak) => Ok(()),
+        Some(_) => message.push(MessageElement::WordBreak),
+    }
+}
+
+/// Runs `build`, and removes everything it added if it fails.
+fn push_all<M: Elements>(
+    message: &mut M,
+    build: impl FnOnce(&mut M) -> Result<(), MessageError>,
+) -> Result<(), MessageError> {
+    let len = message.as_slice().len();
+    let result = build(message);
+
+    if result.is_err() {
+        message.truncate(len);
+    }
+
+    result
+}
+
+fn push_str(message: &mut impl Elements, text: &str) -> Result<(), MessageError> {
+    push_all(message, |message| {
+        text.char_indices().try_for_each(|(byte_offset, c)| {
+            if c.is_whitespace() {
+                return push_word_break(message);
+            }
+
+            let word = MorseWord::try_from(c).map_err(|_| MessageError::InvalidCharacter {
+                character: c,
+                byte_offset,
+            })?;
+
+            message.push(MessageElement::Character(word))
+        })
+    })
+}
+
+fn push_notation(message: &mut impl Elements, notation: &str) -> Result<(), MessageError> {
+    push_all(message, |message| {
+        parse_notation(notation).try_for_each(|item| match item? {
+            NotationItem::Character(word) => message.push(MessageElement::Character(word)),
+            NotationItem::WordBreak => push_word_break(message),
+        })
+    })
+}
+
+/// A message of characters, prosigns, word breaks and gaps, stored in place
+/// with room for up to `N` elements.
+///
+/// Unlike a string, a message can hold prosigns and pauses of any length, and
+/// it can be built from text, from dot-dash notation or element by element.
+/// Play it with [MorsePlayer::play_message](crate::MorsePlayer::play_message).
+/// With the `alloc` feature, `MorseMessageVec` holds any amount of elements.
+///
+/// Whitespace in text and `/` in notation become a single
+/// [MessageElement::WordBreak], which is left out at the start of a message.
+/// When text or notation cannot be added, the message is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{MessageElement, MessageError, MorseMessage, Prosign};
+///
+/// let mut message = MorseMessage::<16>::from_text("CQ  DE").unwrap();
+///
+/// message.push(MessageElement::Gap(14)).unwrap();
+/// message.push_notation("-. ----- / -.-").unwrap();
+/// message.push(Prosign::SK.into()).unwrap();
+///
+/// assert_eq!(message.len(), 11);
+/// assert_eq!(message.elements()[2], MessageElement::WordBreak);
+///
+/// assert_eq!(message.push_str("N0CALL"), Err(MessageError::Full));
+/// assert_eq!(message.len(), 11);
+/// ```
+#[derive(Clone, Copy)]
+pub struct MorseMessage<const N: usize> {
+    elements: [MessageElement; N],
+    len: usize,
+}
+
+impl<const N: usize> MorseMessage<N> {
+    /// Create a new, empty message.
+    pub const fn new() -> Self {
+        MorseMessage {
+            elements: [MessageElement::WordBreak; N],
+            len: 0,
+        }
+    }
+
+    /// Create a message from text, in which whitespace separates words.
+    pub fn from_text(text: &str) -> Result<Self, MessageError> {
+        let mut message = Self::new();
+        message.push_str(text)?;
+        Ok(message)
+    }
+
+    /// Create a message from dot-dash notation, in which `/` separates words.
+    pub fn from_notation(notation: &str) -> Result<Self, MessageError> {
+        let mut message = Self::new();
+        message.push_notation(notation)?;
+        Ok(message)
+    }
+
+    /// Add an element to the end of the message.
+    ///
+    /// Returns [MessageError::Full] if the message already holds `N`
+    /// elements.
+    pub fn push(&mut self, element: MessageElement) -> Result<(), MessageError> {
+        let slot = self.elements.get_mut(self.len).ok_or(MessageError::Full)?;
+
+        *slot = element;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Add the characters of `text` to the end of the message, with
+    /// whitespace separating words.
+    pub fn push_str(&mut self, text: &str) -> Result<(), MessageError> {
+        push_str(self, text)
+    }
+
+    /// Add the characters of dot-dash notation to the end of the message,
+    /// with `/` separating words.
+    pub fn push_notation(&mut self, notation: &str) -> Result<(), MessageError> {
+        push_notation(self, notation)
+    }
+
+    /// Returns the elements of the message.
+    pub fn elements(&self) -> &[MessageElement] {
+        &self.elements[..self.len]
+    }
+
+    /// The amount of elements in the message.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the message holds no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The maximum amount of elements the message can hold.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Remove all elements from the message.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+/// Translate text into a [MorseMessage] in const context, without the `morse!`
+/// macro.
+///
+/// The text is split into words like [MorseMessage::from_text] does, except
+/// that only ASCII whitespace separates words. Any character without a Morse
+/// code representation, or a message that does not fit in `N` elements, is a
+/// compile-time error when evaluated in const context, and a panic otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{encode_str, MorseMessage};
+///
+/// const BEACON: MorseMessage<16> = encode_str("VVV DE N0CALL");
+///
+/// assert_eq!(BEACON, MorseMessage::<16>::from_text("VVV DE N0CALL").unwrap());
+/// assert_eq!(BEACON.len(), 13);
+/// ```
+///
+/// Invalid characters do not compile:
+///
+/// ```compile_fail,E0080
+/// use megamorse::{encode_str, MorseMessage};
+///
+/// const BEACON: MorseMessage<16> = encode_str("VVV DE N0CALL!");
+/// ```
+///
+/// Neither does a message that does not fit in `N` elements:
+///
+/// ```compile_fail,E0080
+/// use megamorse::{encode_str, MorseMessage};
+///
+/// const BEACON: MorseMessage<4> = encode_str("VVV DE");
+/// ```
+pub const fn encode_str<const N: usize>(text: &str) -> MorseMessage<N> {
+    let bytes = text.as_bytes();
+    let mut message = MorseMessage::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let element = match bytes[i] {
+            byte if byte.is_ascii_whitespace() => MessageElement::WordBreak,
+            // All characters with a Morse code representation are ASCII
+            byte if !byte.is_ascii() => panic!("encode_str: text contains an invalid character"),
+            byte => match MorseWord::from_char_const(byte as char) {
+                Some(word) => MessageElement::Character(word),
+                None => panic!("encode_str: text contains an invalid character"),
+            },
+        };
+
+        i += 1;
+
+        if let MessageElement::WordBreak = element {
+            if message.len == 0 {
+                continue;
+            }
+
+            if let MessageElement::WordBreak = message.elements[message.len - 1] {
+                continue;
+            }
+        }
+
+        assert!(
+            message.len < N,
+            "encode_str: message does not fit in N elements"
+        );
+
+        message.elements[message.len] = element;
+        message.len += 1;
+    }
+
+    message
+}
+
+impl<const N: usize> Elements for MorseMessage<N> {
+    fn as_slice(&self) -> &[MessageElement] {
+        self.elements()
+    }
+
+    fn push(&mut self, element: MessageElement) -> Result<(), MessageError> {
+        MorseMessage::push(self, element)
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+}
+
+impl<const N: usize> fmt::Debug for MorseMessage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MorseMessage")
+            .field("elements", &self.elements())
+            .finish()
+    }
+}
+
+impl<const N: usize> FromStr for MorseMessage<N> {
+    type Err = MessageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_text(s)
+    }
+}
+
+impl<const N: usize> Default for MorseMessage<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<MorseMessage<M>> for MorseMessage<N> {
+    fn eq(&self, other: &MorseMessage<M>) -> bool {
+        self.elements() == other.elements()
+    }
+}
+
+impl<const N: usize> Eq for MorseMessage<N> {}
+
+impl<const N: usize> AsRef<[MessageElement]> for MorseMessage<N> {
+    fn as_ref(&self) -> &[MessageElement] {
+        self.elements()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a MorseMessage<N> {
+    type Item = &'a MessageElement;
+    type IntoIter = slice::Iter<'a, MessageElement>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements().iter()
+    }
+}
+
+/// A message of characters, prosigns, word breaks and gaps, like
+/// [MorseMessage], but stored in a [Vec] so that it can hold any amount of
+/// elements.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{MessageElement, MorseMessage, MorseMessageVec};
+///
+/// let mut message = MorseMessageVec::from_notation("... --- ...").unwrap();
+///
+/// message.push(MessageElement::Gap(7));
+/// message.push_str("SOS").unwrap();
+///
+/// assert_eq!(message.len(), 7);
+///
+/// let sos = MorseMessage::<3>::from_text("SOS").unwrap();
+///
+/// assert_eq!(MorseMessageVec::from(sos).elements(), &message.elements()[4..]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MorseMessageVec {
+    elements: Vec<MessageElement>,
+}
+
+#[cfg(feature = "alloc")]
+impl MorseMessageVec {
+    /// Create a new, empty message.
+    pub const fn new() -> Self {
+        MorseMessageVec {
+            elements: Vec::new(),
+        }
+    }
+
+    /// Create a message from text, in which whitespace separates words.
+    pub fn from_text(text: &str) -> Result<Self, MessageError> {
+        let mut message = Self::new();
+        message.push_str(text)?;
+        Ok(message)
+    }
+
+    /// Create a message from dot-dash notation, in which `/` separates words.
+    pub fn from_notation(notation: &str) -> Result<Self, MessageError> {
+        let mut message = Self::new();
+        message.push_notation(notation)?;
+        Ok(message)
+    }
+
+    /// Add an element to the end of the message.
+    pub fn push(&mut self, element: MessageElement) {
+        self.elements.push(element);
+    }
+
+    /// Add the characters of `text` to the end of the message, with
+    /// whitespace separating words.
+    pub fn push_str(&mut self, text: &str) -> Result<(), MessageError> {
+        push_str(self, text)
+    }
+
+    /// Add the characters of dot-dash notation to the end of the message,
+    /// with `/` separating words.
+    pub fn push_notation(&mut self, notation: &str) -> Result<(), MessageError> {
+        push_notation(self, notation)
+    }
+
+    /// Returns the elements of the message.
+    pub fn elements(&self) -> &[MessageElement] {
+        &self.elements
+    }
+
+    /// The amount of elements in the message.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if the message holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Remove all elements from the message.
+    pub fn clear(&mut self) {
+        self.elements.clear();
+    }
+
+    /// Consumes the message, returning its elements.
+    pub fn into_elements(self) -> Vec<MessageElement> {
+        self.elements
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for MorseMessageVec {
+    type Err = MessageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_text(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Elements for MorseMessageVec {
+    fn as_slice(&self) -> &[MessageElement] {
+        &self.elements
+    }
+
+    fn push(&mut self, element: MessageElement) -> Result<(), MessageError> {
+        self.elements.push(element);
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.elements.truncate(len);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<MorseMessage<N>> for MorseMessageVec {
+    fn from(message: MorseMessage<N>) -> Self {
+        MorseMessageVec {
+            elements: message.elements().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Vec<MessageElement>> for MorseMessageVec {
+    fn from(elements: Vec<MessageElement>) -> Self {
+        MorseMessageVec { elements }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<[MessageElement]> for MorseMessageVec {
+    fn as_ref(&self) -> &[MessageElement] {
+        &self.elements
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> IntoIterator for &'a MorseMessageVec {
+    type Item = &'a MessageElement;
+    type IntoIter = slice::Iter<'a, MessageElement>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
@@ -0,0 +1,76 @@
+use core::fmt;
+
+use crate::MorseDecoder;
+use crate::Period;
+use crate::UnitNotation;
+
+/// A [MorseDecoder] that writes everything it is given in unit-level timing
+/// notation, such as `=.===...=`, to a [fmt::Write] implementation.
+///
+/// This renders exactly what a player plays, including the pauses between
+/// characters and words, so it can be used to produce test vectors for any
+/// message.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{MorsePlayer, UnitNotation, UnitNotationWriter};
+///
+/// let mut player = MorsePlayer::new(UnitNotationWriter::new(String::new()));
+///
+/// player.play_str("SOS").unwrap();
+///
+/// assert_eq!(player.decoder().writer(), "=.=.=...===.===.===...=.=.=");
+///
+/// let writer = UnitNotationWriter::new(String::new()).with_notation(UnitNotation::new('1', '0'));
+/// let mut player = MorsePlayer::new(writer);
+///
+/// player.play_str("E E").unwrap();
+///
+/// assert_eq!(player.into_decoder().into_writer(), "100000001");
+/// ```
+#[derive(Debug)]
+pub struct UnitNotationWriter<W> {
+    writer: W,
+    notation: UnitNotation,
+}
+
+impl<W: fmt::Write> UnitNotationWriter<W> {
+    /// Write to `writer` in the standard `=` and `.` notation.
+    pub fn new(writer: W) -> Self {
+        UnitNotationWriter {
+            writer,
+            notation: UnitNotation::STANDARD,
+        }
+    }
+
+    /// Write with custom glyphs.
+    pub fn with_notation(mut self, notation: UnitNotation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Returns a reference to the writer.
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the decoder, returning the writer.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: fmt::Write> MorseDecoder for UnitNotationWriter<W> {
+    type Error = fmt::Error;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.notation
+            .write_period(&mut self.writer, Period::On(timeunits))
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.notation
+            .write_period(&mut self.writer, Period::Off(timeunits))
+    }
+}
@@ -0,0 +1,69 @@
+use core::iter::FusedIterator;
+use core::str::CharIndices;
+
+use megamorse_core::MorseWord;
+use megamorse_core::Prosign;
+
+/// Determines what a [MorsePlayer](crate::MorsePlayer) does when it encounters
+/// a character without a Morse code representation while playing a string.
+///
+/// Set with [MorsePlayer::with_invalid_character_policy](crate::MorsePlayer::with_invalid_character_policy).
+/// The default is [InvalidCharacterPolicy::Reject].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InvalidCharacterPolicy {
+    /// Stop playback and return
+    /// [MorsePlayerError::InvalidCharacter](crate::MorsePlayerError::InvalidCharacter).
+    #[default]
+    Reject,
+
+    /// Leave the character out, as if it was not part of the string.
+    Skip,
+
+    /// Play the given [Prosign] in place of the character, for example
+    /// [Prosign::QUESTION_MARK] or [Prosign::ERROR].
+    Substitute(Prosign),
+}
+
+/// Checks a string for characters without a Morse code representation,
+/// without playing anything.
+///
+/// Returns an iterator over the byte offset and value of every invalid
+/// character, which is empty if the whole string can be played.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::validate;
+///
+/// assert_eq!(validate("CQ DE N0CALL").next(), None);
+///
+/// let mut invalid = validate("Hello, world!");
+///
+/// assert_eq!(invalid.next(), Some((5, ',')));
+/// assert_eq!(invalid.next(), Some((12, '!')));
+/// assert_eq!(invalid.next(), None);
+/// ```
+pub fn validate(source: &str) -> InvalidCharacters<'_> {
+    InvalidCharacters {
+        chars: source.char_indices(),
+    }
+}
+
+/// Iterator over the invalid characters of a string, created by [validate].
+#[derive(Debug, Clone)]
+pub struct InvalidCharacters<'a> {
+    chars: CharIndices<'a>,
+}
+
+impl Iterator for InvalidCharacters<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chars
+            .by_ref()
+            .find(|(_, c)| !c.is_whitespace() && MorseWord::try_from(*c).is_err())
+    }
+}
+
+impl FusedIterator for InvalidCharacters<'_> {}
@@ -0,0 +1,328 @@
+use core::fmt;
+
+use crate::MorseCode;
+use crate::MorseDecoder;
+
+/// Trait representing a push-to-talk (PTT) line of a transmitter.
+///
+/// Used by a [PttDecoder] to put the transmitter into transmit mode before
+/// the key goes down, and back into receive mode afterwards.
+pub trait PttController {
+    /// The error type that the controller can return.
+    type Error;
+
+    /// Assert (`true`) or release (`false`) the PTT line.
+    fn set_ptt(&mut self, active: bool) -> Result<(), Self::Error>;
+}
+
+/// Errors returned by a [PttDecoder].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PttError<DecoderError, ControllerError> {
+    /// An error occurred in the wrapped decoder.
+    Decoder(DecoderError),
+
+    /// An error occurred in the PTT controller.
+    Ptt(ControllerError),
+}
+
+impl<D: fmt::Display, P: fmt::Display> fmt::Display for PttError<D, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PttError::Decoder(error) => write!(f, "decoder error: {}", error),
+            PttError::Ptt(error) => write!(f, "PTT error: {}", error),
+        }
+    }
+}
+
+impl<D, P> core::error::Error for PttError<D, P>
+where
+    D: core::error::Error + 'static,
+    P: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            PttError::Decoder(error) => Some(error),
+            PttError::Ptt(error) => Some(error),
+        }
+    }
+}
+
+/// A [MorseDecoder] that wraps another decoder, and controls the PTT line of
+/// a transmitter around the keyed output.
+///
+/// Before the first element, PTT is asserted and the output stays off for the
+/// lead-in time, giving the transmitter time to switch over. After the last
+/// element, PTT is held for the tail (hang) time, and only released once the
+/// output has been off for longer than that. Messages that follow each other
+/// within the tail time are thus sent in a single transmission. All times are
+/// in time units of the wrapped decoder. The tail defaults to 7 time units, so
+/// that PTT is held between the words of a message.
+///
+/// Time only passes for the [PttDecoder] through its own `off` calls. When the
+/// application waits between messages in some other way, it should call
+/// [PttDecoder::finish] once a transmission is complete.
+///
+/// In full break-in (QSK) mode, PTT follows the key: it is asserted before
+/// every element and released straight after it, so that the operator can
+/// listen between elements. The lead-in time is then only waited before the
+/// first element of a transmission, that is the first element after the
+/// decoder was created, finished or released. See [PttDecoder::with_qsk].
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{assert_notation, Recorder};
+/// use megamorse::{MorseDecoder, MorsePlayer, PttController, PttDecoder};
+///
+/// struct PttPin {
+///     switches: usize,
+/// }
+///
+/// impl PttController for PttPin {
+///     type Error = ();
+///
+///     fn set_ptt(&mut self, _active: bool) -> Result<(), Self::Error> {
+///         self.switches += 1;
+///         Ok(())
+///     }
+/// }
+///
+/// let decoder = PttDecoder::new(Recorder::new(), PttPin { switches: 0 })
+///     .with_lead(2)
+///     .with_tail(10);
+///
+/// let mut player = MorsePlayer::new(decoder);
+///
+/// player.play_str("CQ").unwrap();
+/// // The word pause is shorter than the tail, so PTT stays up
+/// player.decoder_mut().off(7).unwrap();
+/// player.play_str("CQ").unwrap();
+/// player.decoder_mut().finish().unwrap();
+///
+/// assert!(!player.decoder().is_active());
+/// assert_eq!(player.decoder().controller().switches, 2);
+/// assert_notation(player.decoder().decoder().timeline(), "-.-. --.- / -.-. --.-");
+/// ```
+#[derive(Debug)]
+pub struct PttDecoder<D, P> {
+    decoder: D,
+    ptt: P,
+    lead: usize,
+    tail: usize,
+    qsk: bool,
+    active: bool,
+    /// Whether an element has been keyed since PTT was last released for
+    /// good, so that no lead-in time is needed before the next one.
+    keyed: bool,
+    /// Time units the output has been off since the last element.
+    idle: usize,
+}
+
+impl<D: MorseDecoder, P: PttController> PttDecoder<D, P> {
+    /// Wrap `decoder`, controlling `ptt` without lead-in time and with a tail
+    /// time of a word pause.
+    pub fn new(decoder: D, ptt: P) -> Self {
+        PttDecoder {
+            decoder,
+            ptt,
+            lead: 0,
+            tail: 7,
+            qsk: false,
+            active: false,
+            keyed: false,
+            idle: 0,
+        }
+    }
+
+    /// Set the time between asserting PTT and the first element.
+    pub fn with_lead(mut self, timeunits: usize) -> Self {
+        self.lead = timeunits;
+        self
+    }
+
+    /// Set the time PTT is held after the last element. Pauses within a
+    /// message that are longer than this release PTT.
+    pub fn with_tail(mut self, timeunits: usize) -> Self {
+        self.tail = timeunits;
+        self
+    }
+
+    /// Enable or disable full break-in (QSK) mode, in which PTT follows the
+    /// key and the tail time is not used.
+    ///
+    /// The lead-in time is only waited before the first element, until the
+    /// transmission is ended with [PttDecoder::finish] or
+    /// [PttDecoder::release].
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::{Period, Recorder};
+    /// use megamorse::{MorsePlayer, PttController, PttDecoder};
+    ///
+    /// struct PttPin {
+    ///     switches: usize,
+    /// }
+    ///
+    /// impl PttController for PttPin {
+    ///     type Error = ();
+    ///
+    ///     fn set_ptt(&mut self, _active: bool) -> Result<(), Self::Error> {
+    ///         self.switches += 1;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let decoder = PttDecoder::new(Recorder::new(), PttPin { switches: 0 })
+    ///     .with_lead(2)
+    ///     .with_qsk(true);
+    ///
+    /// let mut player = MorsePlayer::new(decoder);
+    ///
+    /// player.play_str("EE").unwrap();
+    /// player.decoder_mut().finish().unwrap();
+    /// player.play_str("E").unwrap();
+    ///
+    /// // PTT was switched around every element, but the lead-in time was
+    /// // only waited at the start of both transmissions
+    /// assert_eq!(player.decoder().controller().switches, 6);
+    /// assert_eq!(
+    ///     player.decoder().decoder().timeline(),
+    ///     [
+    ///         Period::Off(2),
+    ///         Period::On(1),
+    ///         Period::Off(3),
+    ///         Period::On(1),
+    ///         Period::Off(2),
+    ///         Period::On(1),
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_qsk(mut self, qsk: bool) -> Self {
+        self.qsk = qsk;
+        self
+    }
+
+    /// Returns true if PTT is currently asserted.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns a reference to the wrapped decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Returns a reference to the PTT controller.
+    pub fn controller(&self) -> &P {
+        &self.ptt
+    }
+
+    /// Consumes the wrapper, returning the decoder and PTT controller.
+    pub fn into_parts(self) -> (D, P) {
+        (self.decoder, self.ptt)
+    }
+
+    fn set_ptt(&mut self, active: bool) -> Result<(), PttError<D::Error, P::Error>> {
+        self.ptt.set_ptt(active).map_err(PttError::Ptt)?;
+        self.active = active;
+
+        Ok(())
+    }
+
+    /// Assert PTT if needed before a mark, waiting the lead-in time at the
+    /// start of a transmission.
+    fn key_down(&mut self) -> Result<(), PttError<D::Error, P::Error>> {
+        if !self.active {
+            self.set_ptt(true)?;
+
+            if !self.keyed && self.lead > 0 {
+                self.decoder.off(self.lead).map_err(PttError::Decoder)?;
+            }
+        }
+
+        self.keyed = true;
+        self.idle = 0;
+
+        Ok(())
+    }
+
+    /// Release PTT after a mark in QSK mode.
+    fn key_up(&mut self) -> Result<(), PttError<D::Error, P::Error>> {
+        if self.qsk {
+            self.set_ptt(false)?;
+        }
+
+        Ok(())
+    }
+
+    /// Release PTT straight away, without waiting for the tail time.
+    pub fn release(&mut self) -> Result<(), PttError<D::Error, P::Error>> {
+        if self.active {
+            self.set_ptt(false)?;
+        }
+
+        self.keyed = false;
+
+        Ok(())
+    }
+
+    /// Wait out whatever is left of the tail time, and release PTT.
+    pub fn finish(&mut self) -> Result<(), PttError<D::Error, P::Error>> {
+        if self.active {
+            let remaining = self.tail.saturating_sub(self.idle);
+
+            if remaining > 0 {
+                self.decoder.off(remaining).map_err(PttError::Decoder)?;
+            }
+
+            self.set_ptt(false)?;
+        }
+
+        self.keyed = false;
+
+        Ok(())
+    }
+}
+
+impl<D: MorseDecoder, P: PttController> MorseDecoder for PttDecoder<D, P> {
+    type Error = PttError<D::Error, P::Error>;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.key_down()?;
+        self.decoder.on(timeunits).map_err(PttError::Decoder)?;
+        self.key_up()
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        self.key_down()?;
+        self.decoder.on_code(code).map_err(PttError::Decoder)?;
+        self.key_up()
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        let remaining_tail = self.tail.saturating_sub(self.idle);
+
+        if !self.active || timeunits <= remaining_tail {
+            self.idle = self.idle.saturating_add(timeunits);
+            return self.decoder.off(timeunits).map_err(PttError::Decoder);
+        }
+
+        // The tail time runs out during this pause
+        if remaining_tail > 0 {
+            self.decoder
+                .off(remaining_tail)
+                .map_err(PttError::Decoder)?;
+        }
+
+        self.set_ptt(false)?;
+        self.keyed = false;
+        self.idle = self.idle.saturating_add(timeunits);
+
+        self.decoder
+            .off(timeunits - remaining_tail)
+            .map_err(PttError::Decoder)
+    }
+}
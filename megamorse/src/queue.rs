@@ -0,0 +1,413 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::PlaybackCursor;
+use crate::Spacing;
+use crate::TickPlayer;
+
+/// What happens to a message that is preempted by a message with a higher
+/// priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interruption {
+    /// Continue at the character where the message was interrupted.
+    #[default]
+    Resume,
+
+    /// Play the message again from the start.
+    Restart,
+
+    /// Remove the message from the queue.
+    Discard,
+}
+
+/// What a [MessageQueue] does when a message is pushed while it is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Overflow {
+    /// Refuse the new message.
+    #[default]
+    Reject,
+
+    /// Remove the queued message with the lowest priority, if its priority is
+    /// lower than that of the new message. Otherwise, refuse the new message.
+    DropLowest,
+
+    /// Remove the message that has been queued the longest.
+    DropOldest,
+}
+
+/// A message waiting in a [MessageQueue].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueuedMessage<'a> {
+    text: &'a str,
+    priority: u8,
+    interruption: Interruption,
+    cursor: PlaybackCursor,
+}
+
+impl<'a> QueuedMessage<'a> {
+    /// Create a message with priority 0 that resumes when it is interrupted.
+    pub const fn new(text: &'a str) -> Self {
+        QueuedMessage {
+            text,
+            priority: 0,
+            interruption: Interruption::Resume,
+            cursor: PlaybackCursor::START,
+        }
+    }
+
+    /// Set the priority of the message. Messages with a higher priority are
+    /// played first, and preempt messages with a lower priority.
+    pub const fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set what happens when the message is preempted.
+    pub const fn with_interruption(mut self, interruption: Interruption) -> Self {
+        self.interruption = interruption;
+        self
+    }
+
+    /// The text of the message.
+    pub const fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The priority of the message.
+    pub const fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    /// The position of the next character of the message to be played.
+    pub const fn cursor(&self) -> PlaybackCursor {
+        self.cursor
+    }
+
+    /// Take the next character, or None if the message has been played.
+    fn take(&mut self) -> Option<char> {
+        let c = self.text.get(self.cursor.byte_offset()..)?.chars().next()?;
+        self.cursor.advance(c);
+        Some(c)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.cursor.byte_offset() >= self.text.len()
+    }
+}
+
+/// Error returned when pushing to a full [MessageQueue], holding the message
+/// that was refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueFull<'a>(pub QueuedMessage<'a>);
+
+impl fmt::Display for QueueFull<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("message queue is full")
+    }
+}
+
+impl core::error::Error for QueueFull<'_> {}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry<'a> {
+    message: QueuedMessage<'a>,
+    /// Order in which the message was pushed, to keep equal priorities FIFO.
+    order: u32,
+}
+
+/// A bounded queue of up to `N` prioritised messages, played one character at
+/// a time.
+///
+/// The message with the highest priority is played first, and messages with
+/// equal priorities are played in the order they were pushed. Before every
+/// character, the queue checks whether a message with a higher priority has
+/// arrived. If so, that message preempts the current one at this character
+/// boundary, and the current message is resumed, restarted or discarded later
+/// according to its [Interruption] policy. Consecutive messages are separated
+/// by a word pause.
+///
+/// The queue is driven either through a blocking [MorsePlayer] with
+/// [MessageQueue::step], or through a [TickPlayer] with [MessageQueue::tick].
+/// Messages can be pushed between any two calls.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{assert_notation, Recorder};
+/// use megamorse::{Interruption, MessageQueue, MorsePlayer, QueuedMessage};
+///
+/// let mut player = MorsePlayer::new(Recorder::new());
+/// let mut queue = MessageQueue::<4>::new();
+///
+/// queue
+///     .push(QueuedMessage::new("TEMP 21C").with_interruption(Interruption::Resume))
+///     .unwrap();
+///
+/// // Play "TEMP"
+/// for _ in 0..4 {
+///     queue.step(&mut player).unwrap();
+/// }
+///
+/// queue
+///     .push(QueuedMessage::new("SOS").with_priority(10))
+///     .unwrap();
+///
+/// // The urgent message is played completely before the telemetry resumes
+/// for _ in 0..3 {
+///     queue.step(&mut player).unwrap();
+/// }
+///
+/// assert_eq!(queue.len(), 1);
+/// assert_eq!(queue.current().unwrap().text(), "TEMP 21C");
+/// assert_eq!(queue.current().unwrap().cursor().byte_offset(), 4);
+/// assert_notation(player.decoder().timeline(), "- . -- .--. / ... --- ...");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageQueue<'a, const N: usize> {
+    entries: [Option<Entry<'a>>; N],
+    overflow: Overflow,
+    next_order: u32,
+    /// Order of the message that played the last character.
+    playing: Option<u32>,
+    /// Whether a word pause is due before the next character.
+    separate: bool,
+    spacing: Spacing,
+}
+
+impl<const N: usize> Default for MessageQueue<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const N: usize> MessageQueue<'a, N> {
+    /// Create a new, empty queue that rejects messages when it is full.
+    pub const fn new() -> Self {
+        MessageQueue {
+            entries: [None; N],
+            overflow: Overflow::Reject,
+            next_order: 0,
+            playing: None,
+            separate: false,
+            spacing: Spacing::START,
+        }
+    }
+
+    /// Set what happens when a message is pushed while the queue is full.
+    pub const fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// The amount of queued messages, including the one being played.
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Returns true if there are no queued messages.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all queued messages.
+    pub fn clear(&mut self) {
+        self.entries = [None; N];
+
+        if self.playing.take().is_some() {
+            self.separate = true;
+        }
+    }
+
+    /// Returns an iterator over the queued messages, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &QueuedMessage<'a>> + '_ {
+        self.entries.iter().flatten().map(|entry| &entry.message)
+    }
+
+    /// Returns the message that will play the next character.
+    pub fn current(&self) -> Option<&QueuedMessage<'a>> {
+        self.best()
+            .and_then(|index| self.entries[index].as_ref())
+            .map(|entry| &entry.message)
+    }
+
+    /// Add a message to the queue.
+    ///
+    /// If the queue is full, the [Overflow] behaviour of the queue decides
+    /// whether a queued message is removed to make room, or the new message is
+    /// returned in a [QueueFull] error.
+    ///
+    /// A message without any text has nothing to play, so it is dropped
+    /// instead of being queued, and does not interrupt the current message.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use megamorse::testing::{assert_notation, Recorder};
+    /// use megamorse::{MessageQueue, MorsePlayer, QueuedMessage};
+    ///
+    /// let mut player = MorsePlayer::new(Recorder::new());
+    /// let mut queue = MessageQueue::<2>::new();
+    ///
+    /// queue.push(QueuedMessage::new("EE")).unwrap();
+    /// queue.push(QueuedMessage::new("").with_priority(10)).unwrap();
+    ///
+    /// assert_eq!(queue.len(), 1);
+    ///
+    /// while queue.step(&mut player).unwrap() {}
+    ///
+    /// assert_notation(player.decoder().timeline(), ". .");
+    /// ```
+    pub fn push(&mut self, message: QueuedMessage<'a>) -> Result<(), QueueFull<'a>> {
+        if message.is_finished() {
+            return Ok(());
+        }
+
+        let free = match self.entries.iter().position(Option::is_none) {
+            Some(index) => index,
+            None => self.make_room(&message).ok_or(QueueFull(message))?,
+        };
+
+        self.entries[free] = Some(Entry {
+            message,
+            order: self.next_order,
+        });
+        self.next_order = self.next_order.wrapping_add(1);
+
+        Ok(())
+    }
+
+    /// Removes a message according to the overflow behaviour, and returns the
+    /// index that was freed.
+    fn make_room(&mut self, message: &QueuedMessage<'a>) -> Option<usize> {
+        let index = match self.overflow {
+            Overflow::Reject => None,
+            Overflow::DropLowest => self
+                .indexed()
+                .min_by_key(|(_, entry)| (entry.message.priority, core::cmp::Reverse(entry.order)))
+                .filter(|(_, entry)| entry.message.priority < message.priority)
+                .map(|(index, _)| index),
+            Overflow::DropOldest => self
+                .indexed()
+                .min_by_key(|(_, entry)| entry.order)
+                .map(|(index, _)| index),
+        }?;
+
+        self.remove(index);
+
+        Some(index)
+    }
+
+    fn indexed(&self) -> impl Iterator<Item = (usize, &Entry<'a>)> + '_ {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| entry.as_ref().map(|entry| (index, entry)))
+    }
+
+    /// The index of the message with the highest priority, pushed earliest.
+    fn best(&self) -> Option<usize> {
+        self.indexed()
+            .max_by_key(|(_, entry)| (entry.message.priority, core::cmp::Reverse(entry.order)))
+            .map(|(index, _)| index)
+    }
+
+    fn remove(&mut self, index: usize) {
+        if let Some(entry) = self.entries[index].take() {
+            if self.playing == Some(entry.order) {
+                self.playing = None;
+                self.separate = true;
+            }
+        }
+    }
+
+    /// Take the next character to play, switching messages where needed.
+    /// Returns a space between messages, so the player inserts a word pause.
+    fn take(&mut self) -> Option<char> {
+        let best = self.best()?;
+        let order = self.entries[best].as_ref()?.order;
+
+        if self.playing.is_some_and(|playing| playing != order) {
+            self.interrupt();
+        }
+
+        if self.separate {
+            self.separate = false;
+            return Some(' ');
+        }
+
+        let entry = self.entries[best].as_mut()?;
+        let c = entry.message.take();
+
+        self.playing = Some(order);
+
+        if entry.message.is_finished() {
+            self.remove(best);
+        }
+
+        c
+    }
+
+    /// Apply the interruption policy of the message that was playing.
+    fn interrupt(&mut self) {
+        let Some(index) = self
+            .indexed()
+            .find(|(_, entry)| Some(entry.order) == self.playing)
+            .map(|(index, _)| index)
+        else {
+            return;
+        };
+
+        if let Some(entry) = self.entries[index].as_mut() {
+            match entry.message.interruption {
+                Interruption::Resume => {}
+                Interruption::Restart => entry.message.cursor = PlaybackCursor::START,
+                Interruption::Discard => self.entries[index] = None,
+            }
+        }
+
+        self.playing = None;
+        self.separate = true;
+    }
+
+    /// Play the next character of the queue through a blocking player.
+    ///
+    /// Returns false without playing anything if the queue is empty. A
+    /// character counts as played as soon as it is taken from its message, so
+    /// it is not retried if playing it fails.
+    pub fn step<T: MorseDecoder>(
+        &mut self,
+        player: &mut MorsePlayer<T>,
+    ) -> Result<bool, MorsePlayerError<T::Error>> {
+        loop {
+            let cursor = self
+                .current()
+                .map(QueuedMessage::cursor)
+                .unwrap_or(PlaybackCursor::START);
+
+            let Some(c) = self.take() else {
+                return Ok(false);
+            };
+
+            player.play_char(c, cursor, &mut self.spacing, None)?;
+
+            if !c.is_whitespace() {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Advance a [TickPlayer] by one time unit, feeding it the next character
+    /// of the queue whenever it is ready for one.
+    ///
+    /// Returns whether the output should be on during this time unit.
+    pub fn tick(&mut self, player: &mut TickPlayer) -> Result<bool, MorsePlayerError<Infallible>> {
+        player.tick(|| self.take())
+    }
+}
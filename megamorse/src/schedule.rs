@@ -0,0 +1,220 @@
+use core::convert::Infallible;
+use core::iter::FusedIterator;
+use core::str::Chars;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::tick::CharProgram;
+use crate::timing::Keying;
+use crate::validate;
+use crate::InvalidCharacterPolicy;
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::MorseWord;
+use crate::PlaybackCursor;
+use crate::Spacing;
+use crate::Timing;
+
+#[derive(Debug, Clone)]
+enum Source<'a> {
+    Text {
+        chars: Chars<'a>,
+        spacing: Spacing,
+    },
+    Words {
+        words: core::slice::Iter<'a, MorseWord>,
+        first: bool,
+    },
+}
+
+/// An iterator over the marks of a message, as absolute `(start, end)`
+/// intervals measured from the start of the message.
+///
+/// The marks are timed exactly like [MorsePlayer::play_str] would time them,
+/// so the schedule can be used to program hardware timers or DMA transfers
+/// directly, or to check a logic analyzer capture. Times are in time units,
+/// or in microseconds after [MarkSchedule::with_timing].
+///
+/// The schedule is computed one character at a time while iterating, so it
+/// needs no allocation. With the `alloc` feature, `MarkSchedule::into_vec`
+/// collects it into a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{InvalidCharacterPolicy, MarkSchedule, Timing};
+///
+/// let policy = InvalidCharacterPolicy::Reject;
+/// let schedule = MarkSchedule::for_str("AE", policy).unwrap();
+///
+/// // A dot and a dash, the pause between characters, and a dot
+/// assert!(schedule.eq([(0, 1), (2, 5), (8, 9)]));
+///
+/// let schedule = MarkSchedule::for_str("AE", policy)
+///     .unwrap()
+///     .with_timing(Timing::new(1_000).with_weight(60));
+///
+/// assert!(schedule.eq([(0, 1_200), (2_000, 5_200), (8_000, 9_200)]));
+/// ```
+#[derive(Debug)]
+pub struct MarkSchedule<'a> {
+    source: Source<'a>,
+    player: MorsePlayer<CharProgram>,
+    keying: Option<Keying>,
+    time: u64,
+}
+
+impl<'a> MarkSchedule<'a> {
+    fn new(source: Source<'a>, policy: InvalidCharacterPolicy) -> Self {
+        MarkSchedule {
+            source,
+            player: MorsePlayer::new(CharProgram::new()).with_invalid_character_policy(policy),
+            keying: None,
+            time: 0,
+        }
+    }
+
+    /// Create the schedule of `source`, as played by a player with the given
+    /// [InvalidCharacterPolicy].
+    ///
+    /// With [InvalidCharacterPolicy::Reject], returns
+    /// [MorsePlayerError::InvalidCharacter] for the first character that has
+    /// no Morse code representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse::{InvalidCharacterPolicy, MarkSchedule, MorseWord};
+    ///
+    /// assert!(MarkSchedule::for_str("E!", InvalidCharacterPolicy::Reject).is_err());
+    ///
+    /// // The skipped character leaves no trace
+    /// let schedule = MarkSchedule::for_str("E!E", InvalidCharacterPolicy::Skip).unwrap();
+    ///
+    /// assert!(schedule.eq([(0, 1), (4, 5)]));
+    ///
+    /// // The substitute is scheduled like any other character
+    /// let t = MorseWord::try_from('t').unwrap();
+    /// let policy = InvalidCharacterPolicy::Substitute(t.into());
+    /// let schedule = MarkSchedule::for_str("E!", policy).unwrap();
+    ///
+    /// assert!(schedule.eq([(0, 1), (4, 7)]));
+    /// ```
+    pub fn for_str(
+        source: &'a str,
+        policy: InvalidCharacterPolicy,
+    ) -> Result<Self, MorsePlayerError<Infallible>> {
+        if let InvalidCharacterPolicy::Reject = policy {
+            if let Some((byte_offset, character)) = validate(source).next() {
+                return Err(MorsePlayerError::InvalidCharacter {
+                    character,
+                    byte_offset,
+                });
+            }
+        }
+
+        Ok(Self::new(
+            Source::Text {
+                chars: source.chars(),
+                spacing: Spacing::START,
+            },
+            policy,
+        ))
+    }
+
+    /// Create the schedule of `words`, played as the characters of a single
+    /// word.
+    pub fn for_words(words: &'a [MorseWord]) -> Self {
+        // Words are always valid, so the policy does not matter
+        Self::new(
+            Source::Words {
+                words: words.iter(),
+                first: true,
+            },
+            InvalidCharacterPolicy::Reject,
+        )
+    }
+
+    /// Apply `timing` to the schedule, and return times in microseconds
+    /// instead of time units. The marks are adjusted the same way a [Keyer](crate::Keyer)
+    /// adjusts them.
+    pub fn with_timing(mut self, timing: Timing) -> Self {
+        self.keying = Some(Keying::new(timing));
+        self
+    }
+
+    /// Collect the remaining marks into a [Vec].
+    #[cfg(feature = "alloc")]
+    pub fn into_vec(self) -> Vec<(u64, u64)> {
+        self.collect()
+    }
+
+    /// Record the periods of the next character in the program of the
+    /// player. Returns false if the source is exhausted.
+    fn load_next(&mut self) -> bool {
+        self.player.decoder_mut().clear();
+
+        // Playing into the program cannot fail, as the decoder is infallible
+        // and invalid characters were either rejected up front, or are
+        // skipped or substituted as the policy says.
+        match &mut self.source {
+            Source::Text { chars, spacing } => {
+                let Some(c) = chars.next() else {
+                    return false;
+                };
+
+                let _ = self
+                    .player
+                    .play_char(c, PlaybackCursor::START, spacing, None);
+            }
+            Source::Words { words, first } => {
+                let Some(word) = words.next() else {
+                    return false;
+                };
+
+                if !*first {
+                    let _ = self.player.decoder_mut().off(3);
+                }
+
+                *first = false;
+                let _ = self.player.play_word(*word);
+            }
+        }
+
+        true
+    }
+}
+
+impl Iterator for MarkSchedule<'_> {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(period) = self.player.decoder_mut().next_period() else {
+                if !self.load_next() {
+                    return None;
+                }
+
+                continue;
+            };
+
+            let length = match (&mut self.keying, period.on, period.code) {
+                (Some(keying), true, Some(code)) => keying.code(code),
+                (Some(keying), true, None) => keying.mark(period.timeunits),
+                (Some(keying), false, _) => keying.space(period.timeunits),
+                (None, _, _) => period.timeunits as u64,
+            };
+
+            let start = self.time;
+            self.time = self.time.saturating_add(length);
+
+            if period.on {
+                return Some((start, self.time));
+            }
+        }
+    }
+}
+
+impl FusedIterator for MarkSchedule<'_> {}
@@ -0,0 +1,185 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::value::SeqAccessDeserializer;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::message::Elements;
+use crate::MessageElement;
+use crate::MorseMessage;
+#[cfg(feature = "alloc")]
+use crate::MorseMessageVec;
+use crate::MorseWord;
+use crate::Prosign;
+
+/// A [MessageElement] in the compact form used by human-readable messages.
+struct Readable<'a>(&'a MessageElement);
+
+impl Serialize for Readable<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            MessageElement::Character(word) => word.serialize(serializer),
+            MessageElement::Prosign(prosign) => prosign.serialize(serializer),
+            MessageElement::WordBreak => serializer.serialize_str("/"),
+            MessageElement::Gap(timeunits) => serializer.serialize_u64(*timeunits as u64),
+        }
+    }
+}
+
+struct ReadableVisitor;
+
+impl<'de> Visitor<'de> for ReadableVisitor {
+    type Value = MessageElement;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a character, a prosign, a word break or a gap")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<MessageElement, E> {
+        match v {
+            "/" => Ok(MessageElement::WordBreak),
+            _ => v
+                .parse::<MorseWord>()
+                .map(MessageElement::Character)
+                .map_err(|_| E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<MessageElement, E> {
+        usize::try_from(v)
+            .map(MessageElement::Gap)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<MessageElement, A::Error> {
+        Prosign::deserialize(SeqAccessDeserializer::new(seq)).map(MessageElement::Prosign)
+    }
+}
+
+/// Deserializes a [MessageElement] from its compact human-readable form.
+struct ReadableElement(MessageElement);
+
+impl<'de> Deserialize<'de> for ReadableElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_any(ReadableVisitor)
+            .map(ReadableElement)
+    }
+}
+
+fn serialize_elements<S: Serializer>(
+    elements: &[MessageElement],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.collect_seq(elements.iter().map(Readable)),
+        false => serializer.collect_seq(elements),
+    }
+}
+
+struct ElementsVisitor<M> {
+    human_readable: bool,
+    /// The maximum amount of elements, if the message has a fixed capacity.
+    capacity: Option<usize>,
+    message: PhantomData<M>,
+}
+
+impl<'de, M: Elements + Default> Visitor<'de> for ElementsVisitor<M> {
+    type Value = M;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.capacity {
+            Some(capacity) => write!(f, "at most {} message elements", capacity),
+            None => f.write_str("a sequence of message elements"),
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<M, A::Error> {
+        let mut message = M::default();
+
+        loop {
+            let element = match self.human_readable {
+                true => seq
+                    .next_element::<ReadableElement>()?
+                    .map(|element| element.0),
+                false => seq.next_element::<MessageElement>()?,
+            };
+
+            let Some(element) = element else {
+                return Ok(message);
+            };
+
+            let len = message.as_slice().len();
+
+            message
+                .push(element)
+                .map_err(|_| de::Error::invalid_length(len + 1, &self))?;
+        }
+    }
+}
+
+fn deserialize_elements<'de, M: Elements + Default, D: Deserializer<'de>>(
+    deserializer: D,
+    capacity: Option<usize>,
+) -> Result<M, D::Error> {
+    let visitor = ElementsVisitor {
+        human_readable: deserializer.is_human_readable(),
+        capacity,
+        message: PhantomData,
+    };
+
+    deserializer.deserialize_seq(visitor)
+}
+
+/// Serialized as a sequence of its elements. In human-readable formats,
+/// characters are written in dot-dash notation such as `".-"`, word breaks as
+/// `"/"`, prosigns as a sequence of their characters and gaps as a number. In
+/// binary formats, every element is a [MessageElement].
+///
+/// Deserializing fails if the message holds more than `N` elements.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{MessageElement, MorseMessage, Prosign};
+///
+/// let mut message = MorseMessage::<8>::from_text("CQ DE").unwrap();
+///
+/// message.push(MessageElement::Gap(14)).unwrap();
+/// message.push(Prosign::SK.into()).unwrap();
+///
+/// let json = serde_json::to_string(&message).unwrap();
+///
+/// assert_eq!(json, r#"["-.-.","--.-","/","-..",".",14,["...","-.-"]]"#);
+/// assert_eq!(serde_json::from_str::<MorseMessage<8>>(&json).unwrap(), message);
+///
+/// // The message does not fit in 4 elements
+/// assert!(serde_json::from_str::<MorseMessage<4>>(&json).is_err());
+/// ```
+impl<const N: usize> Serialize for MorseMessage<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_elements(self.elements(), serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for MorseMessage<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_elements(deserializer, Some(N))
+    }
+}
+
+/// Serialized like [MorseMessage].
+#[cfg(feature = "alloc")]
+impl Serialize for MorseMessageVec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_elements(self.elements(), serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for MorseMessageVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_elements(deserializer, None)
+    }
+}
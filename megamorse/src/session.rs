@@ -0,0 +1,115 @@
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::PlaybackControl;
+use crate::PlaybackCursor;
+use crate::Spacing;
+
+/// A streaming playback session for a [MorsePlayer], created by
+/// [MorsePlayer::session] or [MorsePlayer::session_with].
+///
+/// Characters are pushed one at a time, for example as they arrive from a
+/// serial port or a keyboard, and are played straight away. The session
+/// remembers what came before, so every character is preceded by the same
+/// pause that [MorsePlayer::play_str] would have played for the whole text:
+/// none before the first character, 3 time units between characters of a word
+/// and 7 time units after whitespace.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{Period, Recorder};
+/// use megamorse::MorsePlayer;
+///
+/// let mut player = MorsePlayer::new(Recorder::new());
+/// let mut session = player.session();
+///
+/// for c in "CQ ".chars() {
+///     session.push_char(c).unwrap();
+/// }
+///
+/// // Nothing more to send for now, so play the pending word pause already
+/// session.flush().unwrap();
+/// session.push_str("DE").unwrap();
+/// session.end().unwrap();
+///
+/// let mut reference = MorsePlayer::new(Recorder::new());
+/// reference.play_str("CQ DE").unwrap();
+///
+/// // The session additionally closed the message with a word pause
+/// let mut expected = reference.into_decoder().into_timeline();
+/// expected.push(Period::Off(7));
+///
+/// assert_eq!(player.decoder().timeline(), expected);
+/// ```
+pub struct MorseSession<'a, T: MorseDecoder> {
+    player: &'a mut MorsePlayer<T>,
+    control: Option<&'a PlaybackControl>,
+    spacing: Spacing,
+    cursor: PlaybackCursor,
+}
+
+impl<'a, T: MorseDecoder> MorseSession<'a, T> {
+    pub(crate) fn new(
+        player: &'a mut MorsePlayer<T>,
+        control: Option<&'a PlaybackControl>,
+    ) -> Self {
+        MorseSession {
+            player,
+            control,
+            spacing: Spacing::START,
+            cursor: PlaybackCursor::START,
+        }
+    }
+
+    /// The position after the last character that was pushed, counted over
+    /// the whole session.
+    pub fn cursor(&self) -> PlaybackCursor {
+        self.cursor
+    }
+
+    /// Play a single character, preceded by the pause it needs after the
+    /// characters pushed before it.
+    ///
+    /// Whitespace is not played straight away, but makes the next character
+    /// start a new word. Characters without a Morse code representation are
+    /// handled according to the player's
+    /// [InvalidCharacterPolicy](crate::InvalidCharacterPolicy), with byte
+    /// offsets counted over the whole session.
+    pub fn push_char(&mut self, c: char) -> Result<(), MorsePlayerError<T::Error>> {
+        self.player
+            .play_char(c, self.cursor, &mut self.spacing, self.control)?;
+        self.cursor.advance(c);
+
+        Ok(())
+    }
+
+    /// Play every character of a string, as if pushed one by one.
+    pub fn push_str(&mut self, s: &str) -> Result<(), MorsePlayerError<T::Error>> {
+        s.chars().try_for_each(|c| self.push_char(c))
+    }
+
+    /// Play the pause that is pending after the characters pushed so far,
+    /// instead of waiting for the next character.
+    ///
+    /// The next character will then only be preceded by whatever part of its
+    /// pause is still missing, so flushing never changes the timing of the
+    /// message. Useful to get the output into a quiet state when no input is
+    /// expected for a while.
+    pub fn flush(&mut self) -> Result<(), MorsePlayerError<T::Error>> {
+        if let Some(pause) = self.spacing.pause() {
+            self.player.off(pause, self.control)?;
+            self.spacing.paused(pause);
+        }
+
+        Ok(())
+    }
+
+    /// Close the message by playing a word pause after the last character,
+    /// so that whatever the player plays next is clearly separated from it.
+    pub fn end(mut self) -> Result<(), MorsePlayerError<T::Error>> {
+        self.spacing.whitespace();
+        self.flush()
+    }
+}
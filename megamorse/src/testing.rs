@@ -0,0 +1,303 @@
+//! Utilities for testing code built on a [MorsePlayer].
+//!
+//! The [Recorder] decoder captures everything a player plays as a timeline of
+//! periods, which can be compared to dot-dash notation with
+//! [assert_notation], or decoded back into text with [receive]. The
+//! [loopback] and [assert_round_trip] functions do both in one go.
+//!
+//! This module is only available with the `testing` feature, which requires
+//! an allocator.
+//!
+//! # Examples
+//!
+//! ```
+//! use megamorse::testing::{assert_notation, Recorder};
+//! use megamorse::{MorsePlayer, Prosign};
+//!
+//! let mut player = MorsePlayer::new(Recorder::new());
+//!
+//! player.play_str("SOS").unwrap();
+//! assert_notation(player.decoder().timeline(), "... --- ...");
+//!
+//! let mut player = MorsePlayer::new(Recorder::new());
+//!
+//! player.play_str("CQ DE").unwrap();
+//! assert_notation(player.decoder().timeline(), "-.-. --.- / -.. .");
+//!
+//! // The words of a prosign run together
+//! let mut player = MorsePlayer::new(Recorder::new());
+//!
+//! player.play_prosign(Prosign::SOS).unwrap();
+//! assert_notation(player.decoder().timeline(), "...---...");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt::Write;
+
+use crate::MorseCode;
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::MorseWord;
+
+#[doc(no_inline)]
+pub use crate::Period;
+
+/// A [MorseDecoder] that records every period it is given.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recorder {
+    timeline: Vec<Period>,
+}
+
+impl Recorder {
+    /// Create a recorder with an empty timeline.
+    pub const fn new() -> Self {
+        Recorder {
+            timeline: Vec::new(),
+        }
+    }
+
+    /// The periods recorded so far, exactly as they were played.
+    pub fn timeline(&self) -> &[Period] {
+        &self.timeline
+    }
+
+    /// Consumes the recorder, returning the recorded periods.
+    pub fn into_timeline(self) -> Vec<Period> {
+        self.timeline
+    }
+
+    /// Forget everything recorded so far.
+    pub fn clear(&mut self) {
+        self.timeline.clear();
+    }
+}
+
+impl MorseDecoder for Recorder {
+    type Error = Infallible;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.timeline.push(Period::On(timeunits));
+        Ok(())
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.timeline.push(Period::Off(timeunits));
+        Ok(())
+    }
+}
+
+/// Merges consecutive periods of the same level and drops empty periods, so
+/// that the timeline alternates between on and off.
+fn merged(timeline: &[Period]) -> Vec<Period> {
+    let mut periods: Vec<Period> = Vec::with_capacity(timeline.len());
+
+    for period in timeline {
+        match (periods.last_mut(), *period) {
+            (_, Period::On(0) | Period::Off(0)) => {}
+            (Some(Period::On(last)), Period::On(units))
+            | (Some(Period::Off(last)), Period::Off(units)) => *last += units,
+            (_, period) => periods.push(period),
+        }
+    }
+
+    periods
+}
+
+/// Render a timeline in dot-dash notation.
+///
+/// Characters are separated by a space and words by `" / "`. Pauses of a
+/// single time unit, including those between the words of a prosign, are not
+/// shown. Periods of any other length than the standard ones are shown as
+/// `{on N}` or `{off N}`, and pauses before the first and after the last mark
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::testing::{notation, Period};
+///
+/// let timeline = [Period::On(1), Period::Off(1), Period::On(3), Period::Off(7), Period::On(40)];
+///
+/// assert_eq!(notation(&timeline), ".- / {on 40}");
+/// ```
+pub fn notation(timeline: &[Period]) -> String {
+    let periods = merged(timeline);
+    let start = periods
+        .iter()
+        .position(|period| matches!(period, Period::On(_)));
+    let end = periods
+        .iter()
+        .rposition(|period| matches!(period, Period::On(_)));
+
+    let (Some(start), Some(end)) = (start, end) else {
+        return String::new();
+    };
+
+    let mut rendered = String::new();
+
+    for period in &periods[start..=end] {
+        // Writing to a String cannot fail
+        let _ = match period {
+            Period::On(1) => write!(rendered, "."),
+            Period::On(3) => write!(rendered, "-"),
+            Period::On(units) => write!(rendered, "{{on {}}}", units),
+            Period::Off(1) => Ok(()),
+            Period::Off(3) => write!(rendered, " "),
+            Period::Off(7) => write!(rendered, " / "),
+            Period::Off(units) => write!(rendered, " {{off {}}} ", units),
+        };
+    }
+
+    rendered
+}
+
+/// Bring dot-dash notation into the form [notation] renders: dashes written
+/// as `-`, and tokens separated by a single space.
+fn normalized(expected: &str) -> String {
+    let mut normal = String::new();
+
+    for token in expected.split_whitespace() {
+        if !normal.is_empty() {
+            normal.push(' ');
+        }
+
+        normal.extend(token.chars().map(|c| if c == '_' { '-' } else { c }));
+    }
+
+    normal
+}
+
+/// Assert that a timeline matches the given dot-dash notation.
+///
+/// The expected notation is written like [notation] renders it, except that
+/// dashes may also be written as `_`, and any amount of whitespace may be used
+/// between characters.
+///
+/// # Panics
+///
+/// Panics with both renderings if the timeline does not match.
+#[track_caller]
+pub fn assert_notation(timeline: &[Period], expected: &str) {
+    let actual = notation(timeline);
+
+    assert_eq!(
+        normalized(&actual),
+        normalized(expected),
+        "timeline does not match the expected Morse code"
+    );
+}
+
+fn push_character(text: &mut String, codes: &[MorseCode]) {
+    match MorseWord::from_slice(codes).and_then(MorseWord::to_char) {
+        Some(c) => text.push(c),
+        None => {
+            text.push('[');
+            text.extend(codes.iter().map(|code| match code {
+                MorseCode::Dot => '.',
+                MorseCode::Dash => '-',
+            }));
+            text.push(']');
+        }
+    }
+}
+
+/// Decode a timeline back into text, like a receiving operator would.
+///
+/// Marks shorter than 2 time units are dots, and longer marks are dashes.
+/// Pauses shorter than 2 time units separate the elements of a character,
+/// pauses shorter than 5 time units separate characters, and longer pauses
+/// separate words. Letters are decoded in uppercase, and characters that have
+/// no text representation, such as prosigns, are shown in dot-dash notation
+/// between square brackets.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::testing::{receive, Recorder};
+/// use megamorse::{MorseDecoder, MorsePlayer, Prosign};
+///
+/// let mut player = MorsePlayer::new(Recorder::new());
+///
+/// player.play_str("73").unwrap();
+/// player.decoder_mut().off(7).unwrap();
+/// player.play_prosign(Prosign::SK).unwrap();
+///
+/// assert_eq!(receive(player.decoder().timeline()), "73 [...-.-]");
+/// ```
+pub fn receive(timeline: &[Period]) -> String {
+    let mut text = String::new();
+    let mut codes = Vec::new();
+
+    for period in merged(timeline) {
+        match period {
+            Period::On(units) if units < 2 => codes.push(MorseCode::Dot),
+            Period::On(_) => codes.push(MorseCode::Dash),
+            Period::Off(units) if units < 2 => {}
+            Period::Off(units) => {
+                if !codes.is_empty() {
+                    push_character(&mut text, &codes);
+                    codes.clear();
+
+                    if units >= 5 {
+                        text.push(' ');
+                    }
+                }
+            }
+        }
+    }
+
+    if !codes.is_empty() {
+        push_character(&mut text, &codes);
+    }
+
+    text
+}
+
+/// Play `text` into a [Recorder], and decode what was played with [receive].
+///
+/// Returns [MorsePlayerError::InvalidCharacter] if the text contains a
+/// character that has no Morse code representation.
+pub fn loopback(text: &str) -> Result<String, MorsePlayerError<Infallible>> {
+    let mut player = MorsePlayer::new(Recorder::new());
+
+    player.play_str(text)?;
+
+    Ok(receive(player.decoder().timeline()))
+}
+
+/// Assert that `text` is received back unchanged after playing it, ignoring
+/// letter case and the amount of whitespace between words.
+///
+/// # Panics
+///
+/// Panics if the text cannot be played, or is received differently.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::testing::assert_round_trip;
+///
+/// assert_round_trip("cq cq  de n0call k");
+/// ```
+#[track_caller]
+pub fn assert_round_trip(text: &str) {
+    let received = match loopback(text) {
+        Ok(received) => received,
+        Err(error) => panic!("cannot play {:?}: {}", text, error),
+    };
+
+    let mut expected = String::new();
+
+    for word in text.split_whitespace() {
+        if !expected.is_empty() {
+            expected.push(' ');
+        }
+
+        expected.extend(word.chars().map(|c| c.to_ascii_uppercase()));
+    }
+
+    assert_eq!(received, expected, "text did not survive the round trip");
+}
@@ -0,0 +1,233 @@
+use core::convert::Infallible;
+
+use crate::InvalidCharacterPolicy;
+use crate::MorseCode;
+use crate::MorseDecoder;
+use crate::MorsePlayer;
+use crate::MorsePlayerError;
+use crate::PlaybackCursor;
+use crate::Spacing;
+
+/// The most periods a single character can take: the pause before it, and a
+/// [Prosign](crate::Prosign) of 4 words of 5 codes each, with the pauses in
+/// between.
+const MAX_PERIODS: usize = 1 + 4 * 9 + 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Step {
+    pub(crate) on: bool,
+    pub(crate) timeunits: usize,
+    /// The dot or dash the period plays, if it is one.
+    pub(crate) code: Option<MorseCode>,
+}
+
+/// A decoder that records the periods of a single character, so that they can
+/// be played back one time unit at a time.
+#[derive(Debug)]
+pub(crate) struct CharProgram {
+    periods: [Step; MAX_PERIODS],
+    len: usize,
+    index: usize,
+    elapsed: usize,
+}
+
+impl CharProgram {
+    pub(crate) const fn new() -> Self {
+        CharProgram {
+            periods: [Step {
+                on: false,
+                timeunits: 0,
+                code: None,
+            }; MAX_PERIODS],
+            len: 0,
+            index: 0,
+            elapsed: 0,
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+        self.index = 0;
+        self.elapsed = 0;
+    }
+
+    fn push(&mut self, step: Step) {
+        debug_assert!(self.len < MAX_PERIODS);
+
+        if step.timeunits > 0 && self.len < MAX_PERIODS {
+            self.periods[self.len] = step;
+            self.len += 1;
+        }
+    }
+
+    /// Returns the next period as a whole, or None if the character has been
+    /// played completely.
+    pub(crate) fn next_period(&mut self) -> Option<Step> {
+        let period = *self.periods[..self.len].get(self.index)?;
+
+        self.index += 1;
+        self.elapsed = 0;
+
+        Some(period)
+    }
+
+    /// Returns the output level for the next time unit, or None if the
+    /// character has been played completely.
+    fn next_unit(&mut self) -> Option<bool> {
+        let period = self.periods[..self.len].get(self.index)?;
+        let on = period.on;
+
+        self.elapsed += 1;
+
+        if self.elapsed == period.timeunits {
+            self.index += 1;
+            self.elapsed = 0;
+        }
+
+        Some(on)
+    }
+}
+
+impl MorseDecoder for CharProgram {
+    type Error = Infallible;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.push(Step {
+            on: true,
+            timeunits,
+            code: None,
+        });
+        Ok(())
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        self.push(Step {
+            on: true,
+            timeunits: code.timeunits(),
+            code: Some(code),
+        });
+        Ok(())
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        self.push(Step {
+            on: false,
+            timeunits,
+            code: None,
+        });
+        Ok(())
+    }
+}
+
+/// A non-blocking Morse code player, driven by calling [TickPlayer::tick] once
+/// every time unit, for example from a timer interrupt.
+///
+/// Instead of sending the output to a [MorseDecoder], every tick returns
+/// whether the output should be on or off during that time unit. Characters
+/// are pulled one at a time from a caller-provided source whenever the
+/// previous character has been played, and are timed exactly like
+/// [MorsePlayer::play_str] would time them. Ticks during which no character
+/// is available count towards the pause before the next character.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::TickPlayer;
+///
+/// let mut player = TickPlayer::new();
+/// let mut text = "EE".chars();
+///
+/// let mut levels = [false; 6];
+///
+/// for level in levels.iter_mut() {
+///     *level = player.tick(|| text.next()).unwrap();
+/// }
+///
+/// // A dot, the pause between characters, another dot, and then idle
+/// assert_eq!(levels, [true, false, false, false, true, false]);
+/// ```
+#[derive(Debug)]
+pub struct TickPlayer {
+    player: MorsePlayer<CharProgram>,
+    spacing: Spacing,
+    cursor: PlaybackCursor,
+}
+
+impl Default for TickPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TickPlayer {
+    /// Create a new, idle tick-driven player.
+    pub fn new() -> Self {
+        TickPlayer {
+            player: MorsePlayer::new(CharProgram::new()),
+            spacing: Spacing::START,
+            cursor: PlaybackCursor::START,
+        }
+    }
+
+    /// Set what the player does with characters that have no Morse code
+    /// representation. See [MorsePlayer::with_invalid_character_policy].
+    pub fn with_invalid_character_policy(mut self, policy: InvalidCharacterPolicy) -> Self {
+        self.player = self.player.with_invalid_character_policy(policy);
+        self
+    }
+
+    /// The position after the last character that was pulled from a source,
+    /// counted over the lifetime of the player.
+    pub fn cursor(&self) -> PlaybackCursor {
+        self.cursor
+    }
+
+    /// Returns true if no character is being played, so that the next tick
+    /// pulls a new character from its source.
+    pub fn is_idle(&self) -> bool {
+        let program = self.player.decoder();
+        program.index >= program.len
+    }
+
+    /// Stop playing the current character, and start the next character
+    /// without a pause, as if it was the first one.
+    pub fn reset(&mut self) {
+        self.player.decoder_mut().clear();
+        self.spacing = Spacing::START;
+    }
+
+    /// Advance the player by one time unit.
+    ///
+    /// If no character is being played, `next` is called to get the next
+    /// character to play, until it returns a character that takes time to
+    /// play or None.
+    ///
+    /// Returns whether the output should be on during this time unit. A
+    /// character without a Morse code representation is consumed and returns
+    /// [MorsePlayerError::InvalidCharacter] if the player's
+    /// [InvalidCharacterPolicy] rejects it.
+    pub fn tick(
+        &mut self,
+        mut next: impl FnMut() -> Option<char>,
+    ) -> Result<bool, MorsePlayerError<Infallible>> {
+        loop {
+            if let Some(on) = self.player.decoder_mut().next_unit() {
+                return Ok(on);
+            }
+
+            let Some(c) = next() else {
+                self.spacing.paused(1);
+                return Ok(false);
+            };
+
+            self.player.decoder_mut().clear();
+
+            let result = self
+                .player
+                .play_char(c, self.cursor, &mut self.spacing, None);
+
+            self.cursor.advance(c);
+            result?;
+        }
+    }
+}
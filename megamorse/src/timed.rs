@@ -0,0 +1,126 @@
+use core::time::Duration;
+
+use crate::timing::Keying;
+use crate::MorseCode;
+use crate::MorseDecoder;
+use crate::Timing;
+
+/// Trait representing a Morse code decoder that works with real durations
+/// instead of time units.
+///
+/// Wrapped in a [Timed], it can be used to construct a [MorsePlayer](crate::MorsePlayer).
+/// The [Timed] wrapper converts the time units of the player with a [Timing],
+/// using saturating arithmetic, so decoders no longer need to convert time
+/// units themselves.
+pub trait DurationDecoder {
+    /// The error type that the decoder can return.
+    type Error;
+
+    /// Set the decoder output to "on" for the given duration. After the
+    /// duration has passed, the output should be turned off.
+    fn on(&mut self, duration: Duration) -> Result<(), Self::Error>;
+
+    /// Set the decoder output to "off" for the given duration.
+    fn off(&mut self, duration: Duration) -> Result<(), Self::Error>;
+}
+
+/// A [MorseDecoder] that converts time units into durations with a [Timing],
+/// and passes them on to a [DurationDecoder].
+///
+/// The weight, dash ratio and compensation of the timing are applied the same
+/// way a [Keyer](crate::Keyer) applies them.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use megamorse::{DurationDecoder, MorsePlayer, Timed, Timing};
+///
+/// struct Clock {
+///     elapsed: Duration,
+/// }
+///
+/// impl DurationDecoder for Clock {
+///     type Error = ();
+///
+///     fn on(&mut self, duration: Duration) -> Result<(), Self::Error> {
+///         self.elapsed += duration;
+///         Ok(())
+///     }
+///
+///     fn off(&mut self, duration: Duration) -> Result<(), Self::Error> {
+///         self.elapsed += duration;
+///         Ok(())
+///     }
+/// }
+///
+/// let clock = Clock { elapsed: Duration::ZERO };
+/// let mut player = MorsePlayer::new(Timed::new(clock, Timing::from_wpm(20)));
+///
+/// player.play_str("PARIS").unwrap();
+///
+/// assert_eq!(player.decoder().decoder().elapsed, Duration::from_millis(43 * 60));
+/// ```
+#[derive(Debug)]
+pub struct Timed<D> {
+    decoder: D,
+    keying: Keying,
+}
+
+impl<D: DurationDecoder> Timed<D> {
+    /// Wrap `decoder`, converting time units with `timing`.
+    pub fn new(decoder: D, timing: Timing) -> Self {
+        Timed {
+            decoder,
+            keying: Keying::new(timing),
+        }
+    }
+
+    /// Returns the timing that is applied.
+    pub fn timing(&self) -> Timing {
+        self.keying.timing
+    }
+
+    /// Change the timing that is applied from the next period on.
+    pub fn set_timing(&mut self, timing: Timing) {
+        self.keying.timing = timing;
+    }
+
+    /// Returns a reference to the wrapped decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Returns a mutable reference to the wrapped decoder.
+    pub fn decoder_mut(&mut self) -> &mut D {
+        &mut self.decoder
+    }
+
+    /// Consumes the wrapper, returning the wrapped decoder.
+    pub fn into_decoder(self) -> D {
+        self.decoder
+    }
+}
+
+impl<D: DurationDecoder> MorseDecoder for Timed<D> {
+    type Error = D::Error;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        let mark = self.keying.mark(timeunits);
+
+        self.decoder.on(Duration::from_micros(mark))
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        let mark = self.keying.code(code);
+
+        self.decoder.on(Duration::from_micros(mark))
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        let space = self.keying.space(timeunits);
+
+        self.decoder.off(Duration::from_micros(space))
+    }
+}
@@ -0,0 +1,431 @@
+use core::fmt;
+
+use crate::MorseCode;
+use crate::MorseDecoder;
+
+/// The amount of time units in the standard word "PARIS", including the word
+/// pause after it. Speeds in words per minute are usually measured with it.
+pub const PARIS_UNITS: usize = 50;
+
+/// The amount of time units in the standard word "CODEX", including the word
+/// pause after it. Used to measure speeds of random-character code groups.
+pub const CODEX_UNITS: usize = 60;
+
+/// Divides a minute, in microseconds, by `divisor`, rounding to the nearest
+/// whole number.
+const fn per_minute(divisor: u64) -> u32 {
+    const MINUTE_US: u64 = 60_000_000;
+
+    if divisor == 0 {
+        return u32::MAX;
+    }
+
+    let quotient = (MINUTE_US + divisor / 2) / divisor;
+
+    if quotient > u32::MAX as u64 {
+        u32::MAX
+    } else {
+        quotient as u32
+    }
+}
+
+/// The real-time timing of keyed output: the length of a time unit, and the
+/// adjustments that make the output sound right on air.
+///
+/// Transmitters and relays tend to shorten or lengthen elements, which makes
+/// the keyed signal sound too light or too heavy. The adjustments correct for
+/// that:
+///
+/// * The weight lengthens (above 50) or shortens (below 50) every mark by a
+///   percentage of a time unit. At a weight of 50, a dot is exactly as long as
+///   the pause that follows it. At 60, it is 1.2 time units long.
+/// * The dash ratio sets the length of a dash relative to a dot, in tenths.
+///   The standard ratio is 30, or 3:1. It only applies to the dashes of
+///   characters, not to other marks such as a carrier.
+/// * The compensation is a fixed amount of microseconds added to every mark.
+///   It can be negative, to make up for a transmitter that holds the key down
+///   for too long.
+///
+/// Whatever is added to a mark is taken from the space that follows it, and
+/// whatever is taken from a mark is added to that space, so the total timing
+/// of a message stays the same.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::{MorseCode, Timing};
+///
+/// // 20 WPM with a heavy weight, a 3.3:1 dash ratio and 500 µs of relay
+/// // compensation
+/// let timing = Timing::new(60_000)
+///     .with_weight(60)
+///     .with_dash_ratio(33)
+///     .with_compensation_us(500);
+///
+/// assert_eq!(timing.code_us(MorseCode::Dot), 60_000 + 12_000 + 500);
+/// assert_eq!(timing.code_us(MorseCode::Dash), 198_000 + 12_000 + 500);
+///
+/// // A carrier of 3 time units is not a dash
+/// assert_eq!(timing.mark_us(3), 180_000 + 12_000 + 500);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timing {
+    unit_us: u32,
+    weight: u8,
+    dash_ratio: u16,
+    compensation_us: i32,
+}
+
+impl Timing {
+    /// Create a timing with a time unit of `unit_us` microseconds, without
+    /// any adjustments.
+    pub const fn new(unit_us: u32) -> Self {
+        Timing {
+            unit_us,
+            weight: 50,
+            dash_ratio: 30,
+            compensation_us: 0,
+        }
+    }
+
+    /// Create a timing for `wpm` words per minute, measured with the standard
+    /// word "PARIS", without any adjustments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `wpm` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse::Timing;
+    ///
+    /// let timing = Timing::from_wpm(20);
+    ///
+    /// assert_eq!(timing.unit_us(), 60_000);
+    /// assert_eq!(timing.cpm(), 100);
+    /// assert_eq!(timing.codex_wpm(), 17);
+    /// ```
+    pub const fn from_wpm(wpm: u32) -> Self {
+        assert!(wpm > 0, "speed must be above zero");
+
+        Self::new(per_minute(PARIS_UNITS as u64 * wpm as u64))
+    }
+
+    /// Create a timing for `wpm` words per minute, measured with the standard
+    /// word "CODEX", without any adjustments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `wpm` is zero.
+    pub const fn from_codex_wpm(wpm: u32) -> Self {
+        assert!(wpm > 0, "speed must be above zero");
+
+        Self::new(per_minute(CODEX_UNITS as u64 * wpm as u64))
+    }
+
+    /// Create a timing for `cpm` characters per minute, counting five
+    /// characters to a "PARIS" word, without any adjustments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cpm` is zero.
+    pub const fn from_cpm(cpm: u32) -> Self {
+        assert!(cpm > 0, "speed must be above zero");
+
+        Self::new(per_minute(PARIS_UNITS as u64 * cpm as u64 / 5))
+    }
+
+    /// The speed in words per minute, measured with the standard word
+    /// "PARIS" and rounded to the nearest whole word. Returns `u32::MAX` for a
+    /// time unit of zero.
+    pub const fn wpm(&self) -> u32 {
+        per_minute(PARIS_UNITS as u64 * self.unit_us as u64)
+    }
+
+    /// The speed in words per minute, measured with the standard word
+    /// "CODEX" and rounded to the nearest whole word. Returns `u32::MAX` for a
+    /// time unit of zero.
+    pub const fn codex_wpm(&self) -> u32 {
+        per_minute(CODEX_UNITS as u64 * self.unit_us as u64)
+    }
+
+    /// The speed in characters per minute, counting five characters to a
+    /// "PARIS" word. Returns `u32::MAX` for a time unit of zero.
+    pub const fn cpm(&self) -> u32 {
+        per_minute(PARIS_UNITS as u64 * self.unit_us as u64 / 5)
+    }
+
+    /// Set the weight, as a percentage. 50 is neutral.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weight` is higher than 100.
+    pub const fn with_weight(mut self, weight: u8) -> Self {
+        assert!(weight <= 100, "weight must be a percentage");
+
+        self.weight = weight;
+        self
+    }
+
+    /// Set the length of a dash relative to a dot, in tenths. 30 is neutral.
+    pub const fn with_dash_ratio(mut self, tenths: u16) -> Self {
+        self.dash_ratio = tenths;
+        self
+    }
+
+    /// Set the amount of microseconds added to every mark, and taken from the
+    /// space that follows it.
+    pub const fn with_compensation_us(mut self, compensation_us: i32) -> Self {
+        self.compensation_us = compensation_us;
+        self
+    }
+
+    /// The length of a time unit, in microseconds.
+    pub const fn unit_us(&self) -> u32 {
+        self.unit_us
+    }
+
+    /// The weight, as a percentage.
+    pub const fn weight(&self) -> u8 {
+        self.weight
+    }
+
+    /// The length of a dash relative to a dot, in tenths.
+    pub const fn dash_ratio(&self) -> u16 {
+        self.dash_ratio
+    }
+
+    /// The amount of microseconds added to every mark.
+    pub const fn compensation_us(&self) -> i32 {
+        self.compensation_us
+    }
+
+    /// The unadjusted length of `timeunits` time units, in microseconds.
+    pub const fn nominal_us(&self, timeunits: usize) -> u64 {
+        (timeunits as u64).saturating_mul(self.unit_us as u64)
+    }
+
+    /// The adjusted length of a mark of `timeunits` time units, in
+    /// microseconds, with the weight and compensation applied.
+    pub const fn mark_us(&self, timeunits: usize) -> u64 {
+        self.adjust(self.nominal_us(timeunits))
+    }
+
+    /// The adjusted length of a dot or dash, in microseconds. Dashes are
+    /// stretched to the dash ratio, and both get the weight and compensation
+    /// applied.
+    pub const fn code_us(&self, code: MorseCode) -> u64 {
+        match code {
+            MorseCode::Dot => self.mark_us(1),
+            MorseCode::Dash => self.adjust(self.unit_us as u64 * self.dash_ratio as u64 / 10),
+        }
+    }
+
+    /// Applies the weight and compensation to a mark of `nominal`
+    /// microseconds.
+    const fn adjust(&self, nominal: u64) -> u64 {
+        let weight = self.unit_us as i64 * (self.weight as i64 - 50) / 50;
+        let mark = (nominal as i64)
+            .saturating_add(weight)
+            .saturating_add(self.compensation_us as i64);
+
+        if mark < 0 {
+            0
+        } else {
+            mark as u64
+        }
+    }
+}
+
+/// A [MorseDecoder] that applies a [Timing] to the time units of a player,
+/// and passes the adjusted lengths on to a decoder in microseconds.
+///
+/// The wrapped decoder receives every period as an amount of microseconds
+/// instead of time units. A period that does not fit in a `usize` is not
+/// passed on, but returned as a [KeyerError::Overflow]. On 16-bit targets,
+/// this already happens for periods longer than about 65 ms, so use a
+/// [Timed](crate::Timed) decoder there instead, which passes on a
+/// [Duration](core::time::Duration). When a mark is lengthened by more than
+/// the space that follows it, the space is played with a length of zero, and
+/// the rest is taken from the next space.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use megamorse::testing::{Period, Recorder};
+/// use megamorse::{morse, Keyer, MorsePlayer, Timing};
+///
+/// let timing = Timing::new(1_000).with_weight(60);
+/// let mut player = MorsePlayer::new(Keyer::new(Recorder::new(), timing));
+///
+/// // "I", two dots
+/// player.play_word(morse!(..)[0]).unwrap();
+///
+/// assert_eq!(
+///     player.decoder().decoder().timeline(),
+///     [Period::On(1_200), Period::Off(800), Period::On(1_200)]
+/// );
+///
+/// // The dash ratio only stretches dashes, not a carrier of the same length
+/// let timing = Timing::new(1_000).with_dash_ratio(40);
+/// let mut player = MorsePlayer::new(Keyer::new(Recorder::new(), timing));
+///
+/// player.play_str("T").unwrap();
+/// player.play_carrier(3).unwrap();
+///
+/// assert_eq!(
+///     player.decoder().decoder().timeline(),
+///     [Period::On(4_000), Period::On(3_000)]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Keyer<D> {
+    decoder: D,
+    keying: Keying,
+}
+
+/// Errors returned by a [Keyer].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyerError<DecoderError> {
+    /// An error occurred in the wrapped decoder.
+    Decoder(DecoderError),
+
+    /// A period of the given amount of microseconds does not fit in a
+    /// `usize`, so it could not be passed on.
+    Overflow(u64),
+}
+
+impl<E: fmt::Display> fmt::Display for KeyerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyerError::Decoder(error) => write!(f, "decoder error: {}", error),
+            KeyerError::Overflow(micros) => {
+                write!(f, "period of {} µs does not fit in a usize", micros)
+            }
+        }
+    }
+}
+
+impl<E> core::error::Error for KeyerError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            KeyerError::Decoder(error) => Some(error),
+            KeyerError::Overflow(_) => None,
+        }
+    }
+}
+
+/// Applies a [Timing] to a stream of marks and spaces, carrying what marks
+/// gain over to the spaces that follow them.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Keying {
+    pub(crate) timing: Timing,
+    /// Microseconds the marks so far were lengthened by, that still have to
+    /// be taken from a space.
+    debt: i64,
+}
+
+impl Keying {
+    pub(crate) const fn new(timing: Timing) -> Self {
+        Keying { timing, debt: 0 }
+    }
+
+    /// The length of the next mark, in microseconds.
+    pub(crate) fn mark(&mut self, timeunits: usize) -> u64 {
+        self.keyed(timeunits, self.timing.mark_us(timeunits))
+    }
+
+    /// The length of the next dot or dash, in microseconds.
+    pub(crate) fn code(&mut self, code: MorseCode) -> u64 {
+        self.keyed(code.timeunits(), self.timing.code_us(code))
+    }
+
+    /// Records that a mark of `timeunits` time units is played as `mark`
+    /// microseconds, and returns `mark`.
+    fn keyed(&mut self, timeunits: usize, mark: u64) -> u64 {
+        let nominal = self.timing.nominal_us(timeunits);
+
+        self.debt = self
+            .debt
+            .saturating_add((mark as i64).saturating_sub(nominal as i64));
+
+        mark
+    }
+
+    /// The length of the next space, in microseconds.
+    pub(crate) fn space(&mut self, timeunits: usize) -> u64 {
+        let nominal = self.timing.nominal_us(timeunits) as i64;
+        let space = nominal.saturating_sub(self.debt).max(0);
+
+        self.debt -= nominal - space;
+
+        space as u64
+    }
+}
+
+impl<D: MorseDecoder> Keyer<D> {
+    /// Wrap `decoder`, which receives periods in microseconds.
+    pub fn new(decoder: D, timing: Timing) -> Self {
+        Keyer {
+            decoder,
+            keying: Keying::new(timing),
+        }
+    }
+
+    /// Returns the timing that is applied.
+    pub fn timing(&self) -> Timing {
+        self.keying.timing
+    }
+
+    /// Change the timing that is applied from the next period on.
+    pub fn set_timing(&mut self, timing: Timing) {
+        self.keying.timing = timing;
+    }
+
+    /// Returns a reference to the wrapped decoder.
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Returns a mutable reference to the wrapped decoder.
+    pub fn decoder_mut(&mut self) -> &mut D {
+        &mut self.decoder
+    }
+
+    /// Consumes the keyer, returning the wrapped decoder.
+    pub fn into_decoder(self) -> D {
+        self.decoder
+    }
+}
+
+fn to_usize<E>(micros: u64) -> Result<usize, KeyerError<E>> {
+    usize::try_from(micros).map_err(|_| KeyerError::Overflow(micros))
+}
+
+impl<D: MorseDecoder> MorseDecoder for Keyer<D> {
+    type Error = KeyerError<D::Error>;
+
+    fn on(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        let mark = to_usize(self.keying.mark(timeunits))?;
+
+        self.decoder.on(mark).map_err(KeyerError::Decoder)
+    }
+
+    fn on_code(&mut self, code: MorseCode) -> Result<(), Self::Error> {
+        let mark = to_usize(self.keying.code(code))?;
+
+        self.decoder.on(mark).map_err(KeyerError::Decoder)
+    }
+
+    fn off(&mut self, timeunits: usize) -> Result<(), Self::Error> {
+        let space = to_usize(self.keying.space(timeunits))?;
+
+        self.decoder.off(space).map_err(KeyerError::Decoder)
+    }
+}
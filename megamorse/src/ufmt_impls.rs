@@ -0,0 +1,93 @@
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::MorsePlayerError;
+
+/// A character shown in quotes. `ufmt` has no [uDebug] implementation for
+/// `char`, as escaping it may panic.
+struct Quoted(char);
+
+impl uDebug for Quoted {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_char('\'')?;
+        f.write_char(self.0)?;
+        f.write_char('\'')
+    }
+}
+
+/// Displays the same message as the [Display](core::fmt::Display)
+/// implementation.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse::MorsePlayerError;
+/// use ufmt::uwrite;
+///
+/// let error = MorsePlayerError::<u8>::InvalidCharacter {
+///     character: '!',
+///     byte_offset: 3,
+/// };
+///
+/// let mut s = String::new();
+/// uwrite!(s, "{}", error).unwrap();
+///
+/// assert_eq!(s, "character '!' at byte offset 3 has no Morse code representation");
+///
+/// s.clear();
+/// uwrite!(s, "{:?}", MorsePlayerError::DecoderError(5u8)).unwrap();
+///
+/// assert_eq!(s, "DecoderError(5)");
+/// ```
+impl<DecoderError: uDisplay> uDisplay for MorsePlayerError<DecoderError> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            MorsePlayerError::InvalidCharacter {
+                character,
+                byte_offset,
+            } => {
+                f.write_str("character ")?;
+                uDebug::fmt(&Quoted(*character), f)?;
+                f.write_str(" at byte offset ")?;
+                uDisplay::fmt(byte_offset, f)?;
+                f.write_str(" has no Morse code representation")
+            }
+            MorsePlayerError::DecoderError(error) => {
+                f.write_str("decoder error: ")?;
+                error.fmt(f)
+            }
+            MorsePlayerError::Aborted => f.write_str("playback was aborted"),
+            MorsePlayerError::InvalidCursor => {
+                f.write_str("playback cursor does not point at a character boundary")
+            }
+        }
+    }
+}
+
+impl<DecoderError: uDebug> uDebug for MorsePlayerError<DecoderError> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            MorsePlayerError::InvalidCharacter {
+                character,
+                byte_offset,
+            } => f
+                .debug_struct("InvalidCharacter")?
+                .field("character", &Quoted(*character))?
+                .field("byte_offset", byte_offset)?
+                .finish(),
+            MorsePlayerError::DecoderError(error) => {
+                f.debug_tuple("DecoderError")?.field(error)?.finish()
+            }
+            MorsePlayerError::Aborted => f.write_str("Aborted"),
+            MorsePlayerError::InvalidCursor => f.write_str("InvalidCursor"),
+        }
+    }
+}
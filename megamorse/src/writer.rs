@@ -0,0 +1,86 @@
+use core::fmt;
+
+use crate::MorseDecoder;
+use crate::MorsePlayerError;
+use crate::MorseSession;
+use crate::PlaybackCursor;
+
+/// A [fmt::Write] adapter for a [MorsePlayer](crate::MorsePlayer), created by
+/// [MorsePlayer::writer](crate::MorsePlayer::writer).
+///
+/// Every string written is played straight away through a [MorseSession], so
+/// formatted text can be keyed with [write!] without an intermediate buffer.
+/// The pause before each character is tracked across writes, so text that is
+/// split over multiple [fmt::Write::write_str] calls sounds the same as if it
+/// was played in one go.
+///
+/// As [fmt::Error] cannot carry any information, the error that stopped
+/// playback is kept in the writer, and can be retrieved with
+/// [MorseWriter::take_error]. Byte offsets in the error count all bytes written
+/// through this writer.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use core::fmt::Write;
+///
+/// use megamorse::testing::{assert_notation, Recorder};
+/// use megamorse::{InvalidCharacterPolicy, MorsePlayer};
+///
+/// let temp = 21;
+///
+/// // "=" has no Morse code representation, so leave it out.
+/// let mut player = MorsePlayer::new(Recorder::new())
+///     .with_invalid_character_policy(InvalidCharacterPolicy::Skip);
+///
+/// write!(player.writer(), "T={} C", temp).unwrap();
+///
+/// assert_notation(player.decoder().timeline(), "- ..--- .---- / -.-.");
+/// ```
+pub struct MorseWriter<'a, T: MorseDecoder> {
+    session: MorseSession<'a, T>,
+    error: Option<MorsePlayerError<T::Error>>,
+}
+
+impl<'a, T: MorseDecoder> MorseWriter<'a, T> {
+    pub(crate) fn new(session: MorseSession<'a, T>) -> Self {
+        MorseWriter {
+            session,
+            error: None,
+        }
+    }
+
+    /// The position after the last character that was played or skipped,
+    /// counted over all strings written so far.
+    pub fn cursor(&self) -> PlaybackCursor {
+        self.session.cursor()
+    }
+
+    /// Returns the session the writer plays through, for example to
+    /// [end](MorseSession::end) the message once everything is written.
+    pub fn into_session(self) -> MorseSession<'a, T> {
+        self.session
+    }
+
+    /// Returns the error that made the last write fail, if any.
+    ///
+    /// Once an error occurred, all further writes fail until the error is
+    /// taken.
+    pub fn take_error(&mut self) -> Option<MorsePlayerError<T::Error>> {
+        self.error.take()
+    }
+}
+
+impl<T: MorseDecoder> fmt::Write for MorseWriter<'_, T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+
+        self.session.push_str(s).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
@@ -0,0 +1,174 @@
+//! Round trips of every serializable type through a human-readable format
+//! (JSON) and a binary format (postcard).
+#![cfg(feature = "serde")]
+
+use core::fmt::Debug;
+
+use megamorse::Interruption;
+use megamorse::InvalidCharacterPolicy;
+use megamorse::MessageElement;
+use megamorse::MorseMessage;
+#[cfg(feature = "alloc")]
+use megamorse::MorseMessageVec;
+use megamorse::MorseWord;
+use megamorse::Overflow;
+use megamorse::PlaybackCursor;
+use megamorse::Prosign;
+use megamorse::Timing;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Checks that `value` serializes to `json` and to `bytes`, and that both
+/// deserialize back to `value`.
+fn round_trip<T>(value: T, json: &str, bytes: &[u8])
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+
+    let mut buffer = [0; 64];
+
+    assert_eq!(postcard::to_slice(&value, &mut buffer).unwrap(), bytes);
+    assert_eq!(postcard::from_bytes::<T>(bytes).unwrap(), value);
+}
+
+fn bits(c: char) -> u8 {
+    MorseWord::try_from(c).unwrap().to_bits()
+}
+
+#[test]
+fn timing() {
+    round_trip(
+        Timing::from_wpm(20)
+            .with_weight(60)
+            .with_compensation_us(-500),
+        r#"{"unit_us":60000,"weight":60,"dash_ratio":30,"compensation_us":-500}"#,
+        &[0xe0, 0xd4, 0x03, 60, 30, 0xe7, 0x07],
+    );
+}
+
+#[test]
+fn playback_cursor() {
+    round_trip(
+        PlaybackCursor::new(3, 2),
+        r#"{"byte_offset":3,"char_index":2}"#,
+        &[3, 2],
+    );
+}
+
+#[test]
+fn message_element() {
+    round_trip(
+        [
+            MessageElement::Character(MorseWord::try_from('a').unwrap()),
+            MessageElement::Prosign(Prosign::SK),
+            MessageElement::WordBreak,
+            MessageElement::Gap(14),
+        ],
+        r#"[{"Character":".-"},{"Prosign":["...","-.-"]},"WordBreak",{"Gap":14}]"#,
+        &[0, bits('a'), 1, 2, bits('s'), bits('k'), 2, 3, 14],
+    );
+}
+
+#[test]
+fn invalid_character_policy() {
+    round_trip(
+        [
+            InvalidCharacterPolicy::Reject,
+            InvalidCharacterPolicy::Skip,
+            InvalidCharacterPolicy::Substitute(Prosign::ERROR),
+        ],
+        r#"["Reject","Skip",{"Substitute":["....","...."]}]"#,
+        &[0, 1, 2, 2, bits('h'), bits('h')],
+    );
+}
+
+#[test]
+fn interruption() {
+    round_trip(
+        [
+            Interruption::Resume,
+            Interruption::Restart,
+            Interruption::Discard,
+        ],
+        r#"["Resume","Restart","Discard"]"#,
+        &[0, 1, 2],
+    );
+
+    assert!(postcard::from_bytes::<Interruption>(&[3]).is_err());
+}
+
+#[test]
+fn overflow() {
+    round_trip(
+        [Overflow::Reject, Overflow::DropLowest, Overflow::DropOldest],
+        r#"["Reject","DropLowest","DropOldest"]"#,
+        &[0, 1, 2],
+    );
+
+    assert!(postcard::from_bytes::<Overflow>(&[3]).is_err());
+}
+
+/// A message with every kind of element, and its JSON and postcard forms.
+fn message() -> (MorseMessage<8>, &'static str, Vec<u8>) {
+    let mut message = MorseMessage::<8>::from_text("CQ DE").unwrap();
+
+    message.push(MessageElement::Gap(14)).unwrap();
+    message.push(Prosign::SK.into()).unwrap();
+
+    let json = r#"["-.-.","--.-","/","-..",".",14,["...","-.-"]]"#;
+    let bytes = vec![
+        7,
+        0,
+        bits('c'),
+        0,
+        bits('q'),
+        2,
+        0,
+        bits('d'),
+        0,
+        bits('e'),
+        3,
+        14,
+        1,
+        2,
+        bits('s'),
+        bits('k'),
+    ];
+
+    (message, json, bytes)
+}
+
+#[test]
+fn morse_message() {
+    let (message, json, bytes) = message();
+
+    round_trip(message, json, &bytes);
+    round_trip(MorseMessage::<4>::new(), "[]", &[0]);
+}
+
+#[test]
+fn morse_message_rejects_more_than_n_elements() {
+    let (_, json, bytes) = message();
+
+    assert!(serde_json::from_str::<MorseMessage<6>>(json).is_err());
+    assert!(postcard::from_bytes::<MorseMessage<6>>(&bytes).is_err());
+    assert!(serde_json::from_str::<MorseMessage<7>>(json).is_ok());
+}
+
+#[test]
+fn morse_message_rejects_invalid_elements() {
+    assert!(serde_json::from_str::<MorseMessage<4>>(r#"["......"]"#).is_err());
+    assert!(serde_json::from_str::<MorseMessage<4>>(r#"["x"]"#).is_err());
+    assert!(serde_json::from_str::<MorseMessage<4>>("[[]]").is_err());
+    assert!(serde_json::from_str::<MorseMessage<4>>("[-1]").is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn morse_message_vec() {
+    let (message, json, bytes) = message();
+
+    round_trip(MorseMessageVec::from(message), json, &bytes);
+}
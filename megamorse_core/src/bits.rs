@@ -0,0 +1,365 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::{MorseCode, MorseWord, NotationItem};
+
+/// Errors returned when packing or unpacking a bitstream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitstreamError {
+    /// The buffer is too small to hold the message.
+    BufferTooSmall,
+
+    /// The buffer ended before the end of the message.
+    UnexpectedEnd,
+
+    /// A length prefix that is not used by the format was found, so the
+    /// buffer does not hold a packed message.
+    InvalidLength,
+}
+
+impl fmt::Display for BitstreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitstreamError::BufferTooSmall => f.write_str("buffer too small for the message"),
+            BitstreamError::UnexpectedEnd => f.write_str("message ends unexpectedly"),
+            BitstreamError::InvalidLength => f.write_str("invalid character length"),
+        }
+    }
+}
+
+impl core::error::Error for BitstreamError {}
+
+/// The amount of bits of the length prefix.
+const LENGTH_BITS: u32 = 3;
+
+/// The length prefix that ends the message.
+const END: u8 = 0;
+
+/// The length prefix of a word break.
+const WORD_BREAK: u8 = 6;
+
+/// Packs a message of [MorseWord]s and word breaks into a byte buffer.
+///
+/// Every character is packed as its amount of dots and dashes in 3 bits,
+/// followed by a `0` for every dot and a `1` for every dash. A length of 6
+/// marks a word break, and a length of 0 ends the message. A character thus
+/// takes at most 8 bits, and the letter `E` 4 bits. Bits are filled in from
+/// the most significant bit of every byte, and the unused bits of the last
+/// byte are set to zero.
+///
+/// Word breaks are only kept between characters: breaks at the start or the
+/// end of a message are dropped, and consecutive breaks are merged into one.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::{BitReader, BitWriter, MorseWord, NotationItem};
+///
+/// let s = MorseWord::try_from('s').unwrap();
+/// let o = MorseWord::try_from('o').unwrap();
+///
+/// let mut buffer = [0; 4];
+/// let mut writer = BitWriter::new(&mut buffer);
+///
+/// writer.write_word(s).unwrap();
+/// writer.write_word(o).unwrap();
+/// writer.write_word(s).unwrap();
+/// writer.write_word_break().unwrap();
+/// writer.write_word(s).unwrap();
+///
+/// // 4 characters of 6 bits, a word break and the end of the message
+/// assert_eq!(writer.finish().unwrap(), 4);
+///
+/// let items: Result<Vec<_>, _> = BitReader::new(&buffer).collect();
+///
+/// assert_eq!(
+///     items.unwrap(),
+///     [
+///         NotationItem::Character(s),
+///         NotationItem::Character(o),
+///         NotationItem::Character(s),
+///         NotationItem::WordBreak,
+///         NotationItem::Character(s),
+///     ]
+/// );
+/// ```
+///
+/// A character of 5 dots and dashes fits in a single byte, next to the end
+/// of the message:
+///
+/// ```
+/// use megamorse_core::{BitWriter, MorseWord};
+///
+/// let mut buffer = [0; 2];
+/// let mut writer = BitWriter::new(&mut buffer);
+///
+/// writer.write_word(MorseWord::try_from('0').unwrap()).unwrap();
+///
+/// assert_eq!(writer.finish().unwrap(), 2);
+/// assert_eq!(buffer, [0b101_11111, 0b000_00000]);
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    buffer: &'a mut [u8],
+    bit: usize,
+    /// A character was written since the start or the last word break.
+    after_character: bool,
+    /// A word break was requested, and is written before the next character.
+    pending_break: bool,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Start packing a message at the start of `buffer`.
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        BitWriter {
+            buffer,
+            bit: 0,
+            after_character: false,
+            pending_break: false,
+        }
+    }
+
+    fn write_bit(&mut self, bit: bool) -> Result<(), BitstreamError> {
+        let byte = self
+            .buffer
+            .get_mut(self.bit / 8)
+            .ok_or(BitstreamError::BufferTooSmall)?;
+        let mask = 0x80 >> (self.bit % 8);
+
+        match bit {
+            true => *byte |= mask,
+            false => *byte &= !mask,
+        }
+
+        self.bit += 1;
+
+        Ok(())
+    }
+
+    fn write_length(&mut self, length: u8) -> Result<(), BitstreamError> {
+        (0..LENGTH_BITS)
+            .rev()
+            .try_for_each(|shift| self.write_bit(length >> shift & 1 != 0))
+    }
+
+    /// Add a character to the message.
+    ///
+    /// When the buffer is too small, part of the character may have been
+    /// written, and the writer should not be used anymore.
+    pub fn write_word(&mut self, word: MorseWord) -> Result<(), BitstreamError> {
+        if self.pending_break {
+            self.write_length(WORD_BREAK)?;
+            self.pending_break = false;
+        }
+
+        let (len, codes) = word.to_array();
+
+        // A word has between 1 and 5 codes, so its length fits
+        self.write_length(len as u8)?;
+
+        for code in &codes[..len] {
+            self.write_bit(*code == MorseCode::Dash)?;
+        }
+
+        self.after_character = true;
+
+        Ok(())
+    }
+
+    /// Add a break between words to the message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse_core::{BitReader, BitWriter, MorseWord, NotationItem};
+    ///
+    /// let e = MorseWord::try_from('e').unwrap();
+    ///
+    /// let mut buffer = [0; 2];
+    /// let mut writer = BitWriter::new(&mut buffer);
+    ///
+    /// // Only the break between the characters is kept
+    /// writer.write_word_break().unwrap();
+    /// writer.write_word(e).unwrap();
+    /// writer.write_word_break().unwrap();
+    /// writer.write_word_break().unwrap();
+    /// writer.write_word(e).unwrap();
+    /// writer.write_word_break().unwrap();
+    ///
+    /// assert_eq!(writer.finish().unwrap(), 2);
+    /// assert_eq!(buffer, [0b001_0_110_0, 0b01_0_000_00]);
+    ///
+    /// let items: Result<Vec<_>, _> = BitReader::new(&buffer).collect();
+    ///
+    /// assert_eq!(
+    ///     items.unwrap(),
+    ///     [
+    ///         NotationItem::Character(e),
+    ///         NotationItem::WordBreak,
+    ///         NotationItem::Character(e),
+    ///     ]
+    /// );
+    /// ```
+    pub fn write_word_break(&mut self) -> Result<(), BitstreamError> {
+        self.pending_break |= self.after_character;
+        self.after_character = false;
+
+        Ok(())
+    }
+
+    /// Add a character or word break to the message.
+    pub fn write_item(&mut self, item: NotationItem) -> Result<(), BitstreamError> {
+        match item {
+            NotationItem::Character(word) => self.write_word(word),
+            NotationItem::WordBreak => self.write_word_break(),
+        }
+    }
+
+    /// End the message, and return the amount of bytes it takes up. The
+    /// unused bits of the last byte are set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse_core::{BitReader, BitWriter};
+    ///
+    /// // An empty message only holds the end of the message
+    /// let mut buffer = [0xff; 1];
+    ///
+    /// assert_eq!(BitWriter::new(&mut buffer).finish().unwrap(), 1);
+    /// assert_eq!(buffer, [0]);
+    /// assert_eq!(BitReader::new(&buffer).next(), None);
+    ///
+    /// // Even that does not fit in an empty buffer
+    /// assert!(BitWriter::new(&mut []).finish().is_err());
+    /// ```
+    pub fn finish(mut self) -> Result<usize, BitstreamError> {
+        self.write_length(END)?;
+
+        let end = self.bit.next_multiple_of(8);
+
+        while self.bit < end {
+            self.write_bit(false)?;
+        }
+
+        Ok(self.bit / 8)
+    }
+}
+
+/// Unpacks a message packed by a [BitWriter], as an iterator over its
+/// characters and word breaks.
+///
+/// The iterator ends at the end of the message, or after returning an error.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::{BitReader, BitWriter, BitstreamError, MorseWord};
+///
+/// let mut buffer = [0; 2];
+/// let mut writer = BitWriter::new(&mut buffer);
+///
+/// writer.write_word(MorseWord::try_from('0').unwrap()).unwrap();
+/// writer.finish().unwrap();
+///
+/// // Without the end of the message, the buffer is truncated
+/// let mut reader = BitReader::new(&buffer[..1]);
+///
+/// assert!(reader.next().unwrap().is_ok());
+/// assert_eq!(reader.next(), Some(Err(BitstreamError::UnexpectedEnd)));
+/// assert_eq!(reader.next(), None);
+///
+/// // A length of 7 is not used by the format
+/// let mut reader = BitReader::new(&[0b111_00000]);
+///
+/// assert_eq!(reader.next(), Some(Err(BitstreamError::InvalidLength)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    buffer: &'a [u8],
+    bit: usize,
+    done: bool,
+}
+
+impl<'a> BitReader<'a> {
+    /// Start unpacking the message at the start of `buffer`.
+    pub const fn new(buffer: &'a [u8]) -> Self {
+        BitReader {
+            buffer,
+            bit: 0,
+            done: false,
+        }
+    }
+
+    /// The amount of bytes read so far. After the end of the message, this
+    /// is the amount of bytes the message takes up.
+    pub const fn bytes_read(&self) -> usize {
+        self.bit.div_ceil(8)
+    }
+
+    fn read_bit(&mut self) -> Result<bool, BitstreamError> {
+        let byte = self
+            .buffer
+            .get(self.bit / 8)
+            .ok_or(BitstreamError::UnexpectedEnd)?;
+        let bit = byte & (0x80 >> (self.bit % 8)) != 0;
+
+        self.bit += 1;
+
+        Ok(bit)
+    }
+
+    fn read_length(&mut self) -> Result<u8, BitstreamError> {
+        (0..LENGTH_BITS).try_fold(0, |length, _| Ok(length << 1 | self.read_bit()? as u8))
+    }
+
+    fn read_item(&mut self) -> Result<Option<NotationItem>, BitstreamError> {
+        match self.read_length()? {
+            END => {
+                // Skip the padding of the last byte
+                self.bit = self.bit.div_ceil(8) * 8;
+
+                Ok(None)
+            }
+            WORD_BREAK => Ok(Some(NotationItem::WordBreak)),
+            length @ 1..=5 => self.read_character(length as usize).map(Some),
+            _ => Err(BitstreamError::InvalidLength),
+        }
+    }
+
+    /// Read the dots and dashes of a character of `len` codes.
+    fn read_character(&mut self, len: usize) -> Result<NotationItem, BitstreamError> {
+        let mut codes = [MorseCode::Dot; 5];
+
+        for code in &mut codes[..len] {
+            *code = match self.read_bit()? {
+                true => MorseCode::Dash,
+                false => MorseCode::Dot,
+            };
+        }
+
+        MorseWord::from_slice(&codes[..len])
+            .map(NotationItem::Character)
+            .ok_or(BitstreamError::InvalidLength)
+    }
+}
+
+impl Iterator for BitReader<'_> {
+    type Item = Result<NotationItem, BitstreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.read_item().transpose();
+
+        if !matches!(item, Some(Ok(_))) {
+            self.done = true;
+        }
+
+        item
+    }
+}
+
+impl FusedIterator for BitReader<'_> {}
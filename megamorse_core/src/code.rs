@@ -6,6 +6,15 @@ pub enum MorseCode {
 }
 
 impl MorseCode {
+    /// The standard length of the code, in time units: 1 for a dot and 3 for
+    /// a dash.
+    pub const fn timeunits(self) -> usize {
+        match self {
+            MorseCode::Dot => 1,
+            MorseCode::Dash => 3,
+        }
+    }
+
     pub(crate) const fn to_bit(self) -> u8 {
         match self {
             MorseCode::Dot => 0,
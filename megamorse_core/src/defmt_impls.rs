@@ -0,0 +1,45 @@
+use defmt::{Format, Formatter};
+
+use crate::{MorseCode, MorseSequence, MorseWord};
+
+/// Formatted as `.` or `-`.
+impl Format for MorseCode {
+    fn format(&self, f: Formatter<'_>) {
+        let glyph = match self {
+            MorseCode::Dot => '.',
+            MorseCode::Dash => '-',
+        };
+
+        defmt::write!(f, "{=char}", glyph)
+    }
+}
+
+/// Formatted as `.` or `-`, or as a space for a pause.
+impl Format for MorseSequence {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            MorseSequence::Code(code) => code.format(f),
+            MorseSequence::Pause => defmt::write!(f, " "),
+        }
+    }
+}
+
+/// Formatted in dot-dash notation, such as `.-`.
+impl Format for MorseWord {
+    fn format(&self, f: Formatter<'_>) {
+        let (len, codes) = self.to_array();
+        let mut notation = [0; 5];
+
+        for (glyph, code) in notation.iter_mut().zip(&codes[..len]) {
+            *glyph = match code {
+                MorseCode::Dot => b'.',
+                MorseCode::Dash => b'-',
+            };
+        }
+
+        // Only ASCII glyphs were written
+        let notation = core::str::from_utf8(&notation[..len]).unwrap_or_default();
+
+        defmt::write!(f, "{=str}", notation)
+    }
+}
@@ -5,13 +5,34 @@
 //! the main Megamorse library.
 #![no_std]
 
+mod bits;
 mod code;
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+mod notation;
+mod prosign;
 mod sequence;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod style;
+#[cfg(feature = "ufmt")]
+mod ufmt_impls;
+mod units;
 mod word;
 
+#[doc(inline)]
+pub use bits::*;
 #[doc(inline)]
 pub use code::*;
 #[doc(inline)]
+pub use notation::*;
+#[doc(inline)]
+pub use prosign::*;
+#[doc(inline)]
 pub use sequence::*;
 #[doc(inline)]
+pub use style::*;
+#[doc(inline)]
+pub use units::*;
+#[doc(inline)]
 pub use word::*;
@@ -0,0 +1,244 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::CharIndices;
+use core::str::FromStr;
+
+use crate::{MorseCode, MorseWord};
+
+/// A single item of a message in dot-dash notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NotationItem {
+    /// A character, written as a group of dots and dashes.
+    Character(MorseWord),
+
+    /// A break between words, written as `/`.
+    WordBreak,
+}
+
+/// What was wrong with a piece of dot-dash notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotationErrorKind {
+    /// A symbol that is not a dot, a dash, whitespace or a word break.
+    InvalidSymbol(char),
+
+    /// A character of more than 5 dots and dashes.
+    TooLong,
+
+    /// There was no character where one was expected.
+    Empty,
+
+    /// There was more than a single character where one was expected.
+    Trailing,
+}
+
+/// Error returned when parsing dot-dash notation, with the position at which
+/// the problem was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotationError {
+    kind: NotationErrorKind,
+    byte_offset: usize,
+}
+
+impl NotationError {
+    /// What was wrong with the notation.
+    pub const fn kind(&self) -> NotationErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the parsed string at which the problem was found.
+    /// For a character that is too long, this is the start of the character.
+    pub const fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            NotationErrorKind::InvalidSymbol(symbol) => write!(
+                f,
+                "invalid Morse symbol {:?} at byte {}",
+                symbol, self.byte_offset
+            ),
+            NotationErrorKind::TooLong => write!(
+                f,
+                "character at byte {} has more than 5 dots and dashes",
+                self.byte_offset
+            ),
+            NotationErrorKind::Empty => f.write_str("no Morse character found"),
+            NotationErrorKind::Trailing => write!(
+                f,
+                "unexpected input after the Morse character at byte {}",
+                self.byte_offset
+            ),
+        }
+    }
+}
+
+impl core::error::Error for NotationError {}
+
+/// Returns the code a symbol of dot-dash notation stands for, if any.
+pub(crate) const fn symbol_code(symbol: char) -> Option<MorseCode> {
+    match symbol {
+        '.' | '·' => Some(MorseCode::Dot),
+        '-' | '_' | '−' => Some(MorseCode::Dash),
+        _ => None,
+    }
+}
+
+/// Parse a message in dot-dash notation, returning an iterator over its
+/// characters and word breaks.
+///
+/// Dots are written as `.` or `·`, and dashes as `-`, `_` or `−`. Characters
+/// are separated by whitespace, and words by a `/`, which does not need to be
+/// surrounded by whitespace. Every `/` is returned as a separate
+/// [NotationItem::WordBreak].
+///
+/// The iterator returns an error at the first problem it finds, and ends
+/// after it.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::{parse_notation, MorseWord, NotationErrorKind, NotationItem};
+///
+/// let mut items = parse_notation("... --- ... / .-. ");
+///
+/// assert_eq!(items.next(), Some(Ok(NotationItem::Character(MorseWord::try_from('s').unwrap()))));
+/// assert_eq!(items.next(), Some(Ok(NotationItem::Character(MorseWord::try_from('o').unwrap()))));
+/// assert_eq!(items.next(), Some(Ok(NotationItem::Character(MorseWord::try_from('s').unwrap()))));
+/// assert_eq!(items.next(), Some(Ok(NotationItem::WordBreak)));
+/// assert_eq!(items.next(), Some(Ok(NotationItem::Character(MorseWord::try_from('r').unwrap()))));
+/// assert_eq!(items.next(), None);
+///
+/// let error = parse_notation("·−· x").find_map(Result::err).unwrap();
+///
+/// assert_eq!(error.kind(), NotationErrorKind::InvalidSymbol('x'));
+/// assert_eq!(error.byte_offset(), 8);
+/// ```
+pub fn parse_notation(notation: &str) -> NotationParser<'_> {
+    NotationParser {
+        chars: notation.char_indices(),
+        pending_break: false,
+        done: false,
+    }
+}
+
+/// An iterator over the characters and word breaks of a message in dot-dash
+/// notation, created by [parse_notation].
+#[derive(Debug, Clone)]
+pub struct NotationParser<'a> {
+    chars: CharIndices<'a>,
+    /// A word break ended the last character, and still has to be returned.
+    pending_break: bool,
+    done: bool,
+}
+
+impl NotationParser<'_> {
+    fn error(&mut self, kind: NotationErrorKind, byte_offset: usize) -> NotationError {
+        self.done = true;
+        NotationError { kind, byte_offset }
+    }
+}
+
+impl Iterator for NotationParser<'_> {
+    type Item = Result<NotationItem, NotationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.pending_break {
+            self.pending_break = false;
+            return Some(Ok(NotationItem::WordBreak));
+        }
+
+        let mut codes = [MorseCode::Dot; 5];
+        let mut len = 0;
+        let mut start = 0;
+
+        for (offset, symbol) in self.chars.by_ref() {
+            if let Some(code) = symbol_code(symbol) {
+                if len == 0 {
+                    start = offset;
+                } else if len == codes.len() {
+                    return Some(Err(self.error(NotationErrorKind::TooLong, start)));
+                }
+
+                codes[len] = code;
+                len += 1;
+            } else if symbol == '/' {
+                if len == 0 {
+                    return Some(Ok(NotationItem::WordBreak));
+                }
+
+                self.pending_break = true;
+                break;
+            } else if symbol.is_whitespace() {
+                if len > 0 {
+                    break;
+                }
+            } else {
+                return Some(Err(
+                    self.error(NotationErrorKind::InvalidSymbol(symbol), offset)
+                ));
+            }
+        }
+
+        MorseWord::from_slice(&codes[..len]).map(|word| Ok(NotationItem::Character(word)))
+    }
+}
+
+impl FusedIterator for NotationParser<'_> {}
+
+/// Parses a single character in dot-dash notation, such as `"-.-"`,
+/// optionally surrounded by whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::MorseWord;
+///
+/// let k: MorseWord = " -.- ".parse().unwrap();
+///
+/// assert_eq!(k.to_char(), Some('K'));
+/// assert!("-.- -".parse::<MorseWord>().is_err());
+/// ```
+impl FromStr for MorseWord {
+    type Err = NotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items = parse_notation(s);
+
+        let word = match items.next() {
+            Some(Ok(NotationItem::Character(word))) => word,
+            Some(Err(error)) => return Err(error),
+            Some(Ok(NotationItem::WordBreak)) | None => {
+                let byte_offset = s.find('/').unwrap_or(0);
+
+                return Err(NotationError {
+                    kind: NotationErrorKind::Empty,
+                    byte_offset,
+                });
+            }
+        };
+
+        // Where the input after the character starts, for the error below
+        let rest = items.chars.as_str();
+        let byte_offset = match items.pending_break {
+            true => s.len() - rest.len() - 1,
+            false => s.len() - rest.trim_start().len(),
+        };
+
+        match items.next() {
+            None => Ok(word),
+            Some(Err(error)) => Err(error),
+            Some(Ok(_)) => Err(NotationError {
+                kind: NotationErrorKind::Trailing,
+                byte_offset,
+            }),
+        }
+    }
+}
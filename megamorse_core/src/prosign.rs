@@ -0,0 +1,122 @@
+use crate::MorseCode;
+use crate::MorseWord;
+
+/// A procedural signal: a short run of [MorseWord]s that is sent without the
+/// usual pause between characters, such as `SK` (end of contact) or `AR` (end
+/// of message).
+///
+/// Run-together characters are also how Morse code represents symbols that
+/// need more than 5 [MorseCode] values, such as the question mark (`IMI`,
+/// `..--..`) or the error signal (`HH`, eight dots).
+///
+/// A [Prosign] holds between 1 and 4 [MorseWord]s.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::{MorseCode, MorseWord, Prosign};
+///
+/// let i = MorseWord::from([MorseCode::Dot, MorseCode::Dot]);
+/// let m = MorseWord::from([MorseCode::Dash, MorseCode::Dash]);
+///
+/// assert_eq!(Prosign::new([i, m, i]), Prosign::QUESTION_MARK);
+/// assert_eq!(Prosign::QUESTION_MARK.words(), &[i, m, i]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prosign {
+    len: u8,
+    words: [MorseWord; 4],
+}
+
+const A: MorseWord = MorseWord::new([MorseCode::Dot, MorseCode::Dash]);
+const B: MorseWord = MorseWord::new([
+    MorseCode::Dash,
+    MorseCode::Dot,
+    MorseCode::Dot,
+    MorseCode::Dot,
+]);
+const H: MorseWord = MorseWord::new([
+    MorseCode::Dot,
+    MorseCode::Dot,
+    MorseCode::Dot,
+    MorseCode::Dot,
+]);
+const I: MorseWord = MorseWord::new([MorseCode::Dot, MorseCode::Dot]);
+const K: MorseWord = MorseWord::new([MorseCode::Dash, MorseCode::Dot, MorseCode::Dash]);
+const M: MorseWord = MorseWord::new([MorseCode::Dash, MorseCode::Dash]);
+const N: MorseWord = MorseWord::new([MorseCode::Dash, MorseCode::Dot]);
+const O: MorseWord = MorseWord::new([MorseCode::Dash, MorseCode::Dash, MorseCode::Dash]);
+const R: MorseWord = MorseWord::new([MorseCode::Dot, MorseCode::Dash, MorseCode::Dot]);
+const S: MorseWord = MorseWord::new([MorseCode::Dot, MorseCode::Dot, MorseCode::Dot]);
+const T: MorseWord = MorseWord::new([MorseCode::Dash]);
+
+impl Prosign {
+    /// `AR`, end of message (`.-.-.`).
+    pub const AR: Prosign = Prosign::new([A, R]);
+
+    /// `BT`, break or new paragraph (`-...-`).
+    pub const BT: Prosign = Prosign::new([B, T]);
+
+    /// `KN`, go ahead, only the called station (`-.--.`).
+    pub const KN: Prosign = Prosign::new([K, N]);
+
+    /// `SK`, end of contact (`...-.-`).
+    pub const SK: Prosign = Prosign::new([S, K]);
+
+    /// `SOS`, distress signal (`...---...`).
+    pub const SOS: Prosign = Prosign::new([S, O, S]);
+
+    /// `HH`, the error signal (`........`).
+    pub const ERROR: Prosign = Prosign::new([H, H]);
+
+    /// `IMI`, the question mark (`..--..`).
+    pub const QUESTION_MARK: Prosign = Prosign::new([I, M, I]);
+
+    /// Creates a [Prosign] from the [MorseWord]s that are run together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty or contains more than 4 words. In const
+    /// context, this is a compile-time error.
+    pub const fn new<const N: usize>(words: [MorseWord; N]) -> Self {
+        match Prosign::from_slice(&words) {
+            Some(prosign) => prosign,
+            None => panic!("a Prosign holds between 1 and 4 words"),
+        }
+    }
+
+    /// Creates a [Prosign] from a slice of [MorseWord]s, for when the amount
+    /// of words is only known at runtime.
+    ///
+    /// Returns None if the slice is empty or contains more than 4 words.
+    pub const fn from_slice(words: &[MorseWord]) -> Option<Self> {
+        if words.is_empty() || words.len() > 4 {
+            return None;
+        }
+
+        let mut padded = [words[0]; 4];
+        let mut i = 1;
+
+        while i < words.len() {
+            padded[i] = words[i];
+            i += 1;
+        }
+
+        Some(Prosign {
+            len: words.len() as u8,
+            words: padded,
+        })
+    }
+
+    /// Returns the [MorseWord]s that are run together to form this [Prosign].
+    pub fn words(&self) -> &[MorseWord] {
+        &self.words[..self.len as usize]
+    }
+}
+
+/// A single [MorseWord] is a [Prosign] that consists of only that word.
+impl From<MorseWord> for Prosign {
+    fn from(word: MorseWord) -> Self {
+        Prosign::new([word])
+    }
+}
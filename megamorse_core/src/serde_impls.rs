@@ -0,0 +1,281 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::notation::symbol_code;
+use crate::{MorseCode, MorseSequence, MorseWord, Prosign};
+
+/// A type that is a dot-dash string in human-readable formats, and a single
+/// byte in binary formats.
+trait Compact: Sized {
+    const EXPECTING: &'static str;
+
+    fn from_notation(s: &str) -> Option<Self>;
+
+    fn from_byte(byte: u8) -> Option<Self>;
+}
+
+struct CompactVisitor<T>(PhantomData<T>);
+
+impl<T: Compact> Visitor<'_> for CompactVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_notation(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        u8::try_from(v)
+            .ok()
+            .and_then(T::from_byte)
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+}
+
+fn deserialize_compact<'de, T: Compact, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    match deserializer.is_human_readable() {
+        true => deserializer.deserialize_str(CompactVisitor(PhantomData)),
+        false => deserializer.deserialize_u8(CompactVisitor(PhantomData)),
+    }
+}
+
+/// Returns the only character of `s`, if it has exactly one.
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+impl Compact for MorseCode {
+    const EXPECTING: &'static str = "a dot or a dash";
+
+    fn from_notation(s: &str) -> Option<Self> {
+        single_char(s).and_then(symbol_code)
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(MorseCode::Dot),
+            1 => Some(MorseCode::Dash),
+            _ => None,
+        }
+    }
+}
+
+impl Compact for MorseSequence {
+    const EXPECTING: &'static str = "a dot, a dash or a pause";
+
+    fn from_notation(s: &str) -> Option<Self> {
+        match s {
+            " " => Some(MorseSequence::Pause),
+            _ => MorseCode::from_notation(s).map(MorseSequence::Code),
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            2 => Some(MorseSequence::Pause),
+            _ => MorseCode::from_byte(byte).map(MorseSequence::Code),
+        }
+    }
+}
+
+impl Compact for MorseWord {
+    const EXPECTING: &'static str = "a character in dot-dash notation";
+
+    fn from_notation(s: &str) -> Option<Self> {
+        s.parse().ok()
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        MorseWord::from_bits(byte)
+    }
+}
+
+/// Serialized as `"."` or `"-"` in human-readable formats, and as the byte `0`
+/// or `1` in binary formats.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::MorseCode;
+///
+/// assert_eq!(serde_json::to_string(&MorseCode::Dash).unwrap(), r#""-""#);
+/// assert_eq!(serde_json::from_str::<MorseCode>(r#"".""#).unwrap(), MorseCode::Dot);
+/// assert!(serde_json::from_str::<MorseCode>(r#""x""#).is_err());
+///
+/// let mut buffer = [0; 1];
+///
+/// assert_eq!(postcard::to_slice(&MorseCode::Dash, &mut buffer).unwrap(), [1]);
+/// assert_eq!(postcard::from_bytes::<MorseCode>(&buffer).unwrap(), MorseCode::Dash);
+/// assert!(postcard::from_bytes::<MorseCode>(&[2]).is_err());
+/// ```
+impl Serialize for MorseCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.serialize_str(match self {
+                MorseCode::Dot => ".",
+                MorseCode::Dash => "-",
+            }),
+            false => serializer.serialize_u8(self.to_bit()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MorseCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer)
+    }
+}
+
+/// Serialized as `"."`, `"-"` or `" "` (a pause) in human-readable formats,
+/// and as the byte `0`, `1` or `2` in binary formats.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::{MorseCode, MorseSequence};
+///
+/// let sequence = [
+///     MorseSequence::Code(MorseCode::Dot),
+///     MorseSequence::Pause,
+///     MorseSequence::Code(MorseCode::Dash),
+/// ];
+///
+/// let json = serde_json::to_string(&sequence).unwrap();
+///
+/// assert_eq!(json, r#"["."," ","-"]"#);
+/// assert_eq!(serde_json::from_str::<[MorseSequence; 3]>(&json).unwrap(), sequence);
+///
+/// let mut buffer = [0; 3];
+///
+/// assert_eq!(postcard::to_slice(&sequence, &mut buffer).unwrap(), [0, 2, 1]);
+/// assert_eq!(postcard::from_bytes::<[MorseSequence; 3]>(&buffer).unwrap(), sequence);
+/// assert!(postcard::from_bytes::<MorseSequence>(&[3]).is_err());
+/// ```
+impl Serialize for MorseSequence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self, serializer.is_human_readable()) {
+            (MorseSequence::Code(code), _) => code.serialize(serializer),
+            (MorseSequence::Pause, true) => serializer.serialize_str(" "),
+            (MorseSequence::Pause, false) => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MorseSequence {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer)
+    }
+}
+
+/// Serialized in dot-dash notation, such as `".-"`, in human-readable
+/// formats, and as the single byte returned by [MorseWord::to_bits] in binary
+/// formats.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::MorseWord;
+///
+/// let a = MorseWord::try_from('a').unwrap();
+///
+/// assert_eq!(serde_json::to_string(&a).unwrap(), r#"".-""#);
+/// assert_eq!(serde_json::from_str::<MorseWord>(r#"".-""#).unwrap(), a);
+/// assert!(serde_json::from_str::<MorseWord>(r#""......""#).is_err());
+///
+/// let mut buffer = [0; 1];
+///
+/// assert_eq!(postcard::to_slice(&a, &mut buffer).unwrap(), [a.to_bits()]);
+/// assert_eq!(postcard::from_bytes::<MorseWord>(&buffer).unwrap(), a);
+/// assert!(postcard::from_bytes::<MorseWord>(&[0]).is_err());
+/// ```
+impl Serialize for MorseWord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => serializer.serialize_u8(self.to_bits()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MorseWord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer)
+    }
+}
+
+/// Serialized as a sequence of its [MorseWord]s.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::Prosign;
+///
+/// let json = serde_json::to_string(&Prosign::SK).unwrap();
+///
+/// assert_eq!(json, r#"["...","-.-"]"#);
+/// assert_eq!(serde_json::from_str::<Prosign>(&json).unwrap(), Prosign::SK);
+/// assert!(serde_json::from_str::<Prosign>("[]").is_err());
+///
+/// let mut buffer = [0; 8];
+/// let bytes = postcard::to_slice(&Prosign::SK, &mut buffer).unwrap();
+///
+/// assert_eq!(bytes.len(), 3);
+/// assert_eq!(postcard::from_bytes::<Prosign>(bytes).unwrap(), Prosign::SK);
+/// ```
+impl Serialize for Prosign {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let words = self.words();
+        let mut seq = serializer.serialize_seq(Some(words.len()))?;
+
+        for word in words {
+            seq.serialize_element(word)?;
+        }
+
+        seq.end()
+    }
+}
+
+struct ProsignVisitor;
+
+impl<'de> Visitor<'de> for ProsignVisitor {
+    type Value = Prosign;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("between 1 and 4 characters")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Prosign, A::Error> {
+        let mut words = [MorseWord::new([MorseCode::Dot]); 4];
+        let mut len = 0;
+
+        while let Some(word) = seq.next_element()? {
+            if len == words.len() {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+
+            words[len] = word;
+            len += 1;
+        }
+
+        Prosign::from_slice(&words[..len]).ok_or_else(|| de::Error::invalid_length(len, &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Prosign {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ProsignVisitor)
+    }
+}
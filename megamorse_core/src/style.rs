@@ -0,0 +1,274 @@
+use core::fmt;
+use core::fmt::Write;
+
+use crate::{MorseCode, MorseWord, Prosign};
+
+/// How dot-dash notation is rendered: the glyphs used for dots and dashes, and
+/// the separators between characters and between words.
+///
+/// The [Display](core::fmt::Display) implementations of [MorseCode],
+/// [MorseWord] and [Prosign] use [NotationStyle::ASCII], or
+/// [NotationStyle::UNICODE] with the alternate flag (`{:#}`). Other styles are
+/// applied with their `styled` methods.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::{text_notation, MorseWord, NotationStyle};
+///
+/// let k = MorseWord::try_from('k').unwrap();
+///
+/// assert_eq!(format!("{}", k), "-.-");
+/// assert_eq!(format!("{:#}", k), "−·−");
+/// assert_eq!(k.styled(NotationStyle::ASCII.with_dash('_')).to_string(), "_._");
+///
+/// let style = NotationStyle::UNICODE.with_word_separator(" | ");
+///
+/// assert_eq!(
+///     text_notation("CQ DE").with_style(style).to_string(),
+///     "−·−· −−·− | −·· ·"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotationStyle {
+    dot: char,
+    dash: char,
+    character_separator: &'static str,
+    word_separator: &'static str,
+}
+
+impl NotationStyle {
+    /// Dots as `.`, dashes as `-`, characters separated by a space and words
+    /// by `" / "`. This is the notation [parse_notation](crate::parse_notation)
+    /// reads.
+    pub const ASCII: NotationStyle = NotationStyle {
+        dot: '.',
+        dash: '-',
+        character_separator: " ",
+        word_separator: " / ",
+    };
+
+    /// Like [NotationStyle::ASCII], but with a middle dot (`·`) for dots and
+    /// a minus sign (`−`) for dashes.
+    pub const UNICODE: NotationStyle = NotationStyle {
+        dot: '·',
+        dash: '−',
+        ..NotationStyle::ASCII
+    };
+
+    /// Set the glyph used for dots.
+    pub const fn with_dot(mut self, dot: char) -> Self {
+        self.dot = dot;
+        self
+    }
+
+    /// Set the glyph used for dashes.
+    pub const fn with_dash(mut self, dash: char) -> Self {
+        self.dash = dash;
+        self
+    }
+
+    /// Set the separator written between the characters of a word.
+    pub const fn with_character_separator(mut self, separator: &'static str) -> Self {
+        self.character_separator = separator;
+        self
+    }
+
+    /// Set the separator written between words.
+    pub const fn with_word_separator(mut self, separator: &'static str) -> Self {
+        self.word_separator = separator;
+        self
+    }
+
+    /// The glyph used for dots.
+    pub const fn dot(&self) -> char {
+        self.dot
+    }
+
+    /// The glyph used for dashes.
+    pub const fn dash(&self) -> char {
+        self.dash
+    }
+
+    /// The separator written between the characters of a word.
+    pub const fn character_separator(&self) -> &'static str {
+        self.character_separator
+    }
+
+    /// The separator written between words.
+    pub const fn word_separator(&self) -> &'static str {
+        self.word_separator
+    }
+
+    /// The glyph for `code`.
+    pub const fn glyph(&self, code: MorseCode) -> char {
+        match code {
+            MorseCode::Dot => self.dot,
+            MorseCode::Dash => self.dash,
+        }
+    }
+
+    /// The style the [Display](core::fmt::Display) implementations use for
+    /// the given formatter.
+    fn of(f: &fmt::Formatter<'_>) -> Self {
+        match f.alternate() {
+            true => NotationStyle::UNICODE,
+            false => NotationStyle::ASCII,
+        }
+    }
+}
+
+impl Default for NotationStyle {
+    fn default() -> Self {
+        NotationStyle::ASCII
+    }
+}
+
+/// A value that is displayed in dot-dash notation with a [NotationStyle].
+/// Created by the `styled` methods of [MorseCode], [MorseWord] and [Prosign].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Styled<T> {
+    value: T,
+    style: NotationStyle,
+}
+
+impl MorseCode {
+    /// Display this code with the given style.
+    pub const fn styled(self, style: NotationStyle) -> Styled<Self> {
+        Styled { value: self, style }
+    }
+}
+
+impl MorseWord {
+    /// Display this word with the given style.
+    pub const fn styled(self, style: NotationStyle) -> Styled<Self> {
+        Styled { value: self, style }
+    }
+}
+
+impl Prosign {
+    /// Display this prosign with the given style. Its words are run together
+    /// without a separator.
+    pub const fn styled(self, style: NotationStyle) -> Styled<Self> {
+        Styled { value: self, style }
+    }
+}
+
+impl fmt::Display for Styled<MorseCode> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char(self.style.glyph(self.value))
+    }
+}
+
+impl fmt::Display for Styled<MorseWord> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (len, codes) = self.value.to_array();
+
+        codes[..len]
+            .iter()
+            .try_for_each(|code| f.write_char(self.style.glyph(*code)))
+    }
+}
+
+impl fmt::Display for Styled<Prosign> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value
+            .words()
+            .iter()
+            .try_for_each(|word| word.styled(self.style).fmt(f))
+    }
+}
+
+/// Displays the code as `.` or `-`, or as `·` or `−` with `{:#}`.
+impl fmt::Display for MorseCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.styled(NotationStyle::of(f)).fmt(f)
+    }
+}
+
+/// Displays the word in dot-dash notation, such as `.-`, or `·−` with `{:#}`.
+impl fmt::Display for MorseWord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.styled(NotationStyle::of(f)).fmt(f)
+    }
+}
+
+/// Displays the prosign in dot-dash notation with its words run together,
+/// such as `...-.-`, or `···−·−` with `{:#}`.
+impl fmt::Display for Prosign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.styled(NotationStyle::of(f)).fmt(f)
+    }
+}
+
+/// Text that is displayed in dot-dash notation, created by [text_notation].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextNotation<'a> {
+    text: &'a str,
+    style: Option<NotationStyle>,
+}
+
+impl TextNotation<'_> {
+    /// Display the text with the given style, instead of the style selected
+    /// by the formatter.
+    pub const fn with_style(mut self, style: NotationStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl fmt::Display for TextNotation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.style.unwrap_or_else(|| NotationStyle::of(f));
+        let mut started = false;
+        let mut word = false;
+
+        for c in self.text.chars() {
+            if c.is_whitespace() {
+                word = true;
+                continue;
+            }
+
+            if started {
+                f.write_str(match word {
+                    true => style.word_separator,
+                    false => style.character_separator,
+                })?;
+            }
+
+            match MorseWord::try_from(c) {
+                Ok(mword) => mword.styled(style).fmt(f)?,
+                Err(_) => f.write_char(c)?,
+            }
+
+            started = true;
+            word = false;
+        }
+
+        Ok(())
+    }
+}
+
+/// Translate text into dot-dash notation, for example to show what
+/// `MorsePlayer::play_str` is about to key.
+///
+/// Characters are separated and words are split exactly like they are when the
+/// text is played: whitespace before the first and after the last character
+/// is ignored, and any run of whitespace separates two words. Characters
+/// without a Morse code representation are written unchanged.
+///
+/// The result is displayed in [NotationStyle::ASCII], in
+/// [NotationStyle::UNICODE] with `{:#}`, or in any other style with
+/// [TextNotation::with_style].
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::text_notation;
+///
+/// assert_eq!(text_notation(" SOS  sos ").to_string(), "... --- ... / ... --- ...");
+/// assert_eq!(format!("{:#}", text_notation("73!")), "−−··· ···−− !");
+/// ```
+pub const fn text_notation(text: &str) -> TextNotation<'_> {
+    TextNotation { text, style: None }
+}
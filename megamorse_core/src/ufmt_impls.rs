@@ -0,0 +1,93 @@
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::{MorseCode, MorseSequence, MorseWord};
+
+/// Displays the code as `.` or `-`.
+impl uDisplay for MorseCode {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_char(match self {
+            MorseCode::Dot => '.',
+            MorseCode::Dash => '-',
+        })
+    }
+}
+
+impl uDebug for MorseCode {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(match self {
+            MorseCode::Dot => "Dot",
+            MorseCode::Dash => "Dash",
+        })
+    }
+}
+
+/// Displays the sequence as `.` or `-`, or as a space for a pause.
+impl uDisplay for MorseSequence {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            MorseSequence::Code(code) => uDisplay::fmt(code, f),
+            MorseSequence::Pause => f.write_char(' '),
+        }
+    }
+}
+
+impl uDebug for MorseSequence {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            MorseSequence::Code(code) => f.debug_tuple("Code")?.field(code)?.finish(),
+            MorseSequence::Pause => f.write_str("Pause"),
+        }
+    }
+}
+
+/// Displays the word in dot-dash notation, such as `.-`.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::MorseWord;
+/// use ufmt::uwrite;
+///
+/// let a = MorseWord::try_from('a').unwrap();
+/// let mut s = String::new();
+///
+/// uwrite!(s, "{} {:?}", a, a).unwrap();
+///
+/// assert_eq!(s, r#".- MorseWord(".-")"#);
+/// ```
+impl uDisplay for MorseWord {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let (len, codes) = self.to_array();
+
+        codes[..len]
+            .iter()
+            .try_for_each(|code| uDisplay::fmt(code, f))
+    }
+}
+
+/// Shows the word in dot-dash notation, such as `MorseWord(".-")`.
+impl uDebug for MorseWord {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("MorseWord(\"")?;
+        uDisplay::fmt(self, f)?;
+        f.write_str("\")")
+    }
+}
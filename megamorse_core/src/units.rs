@@ -0,0 +1,237 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::CharIndices;
+
+use crate::{MorseCode, MorseSequence};
+
+/// A single period of a keyed timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Period {
+    /// The output is on (a mark) for the given amount of time units.
+    On(usize),
+
+    /// The output is off (a space) for the given amount of time units.
+    Off(usize),
+}
+
+/// Error returned when parsing unit-level timing notation that contains a
+/// symbol other than the mark and space glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitNotationError {
+    symbol: char,
+    byte_offset: usize,
+}
+
+impl UnitNotationError {
+    /// The symbol that is neither a mark nor a space.
+    pub const fn symbol(&self) -> char {
+        self.symbol
+    }
+
+    /// The byte offset of the symbol in the parsed string.
+    pub const fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+}
+
+impl fmt::Display for UnitNotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid timing symbol {:?} at byte {}",
+            self.symbol, self.byte_offset
+        )
+    }
+}
+
+impl core::error::Error for UnitNotationError {}
+
+/// Unit-level timing notation, in which every time unit is written as a single
+/// glyph: a mark glyph while the output is on, and a space glyph while it is
+/// off. The standard glyphs are `=` and `.`, so that "SOS" is written as
+/// `=.=.=...===.===.===...=.=.=`.
+///
+/// Unlike dot-dash notation, this shows the exact length of every element and
+/// gap, which makes it useful for specifications and test vectors.
+///
+/// # Examples
+///
+/// ```
+/// use megamorse_core::{MorseWord, Period, UnitNotation};
+///
+/// let notation = UnitNotation::STANDARD;
+/// let mut rendered = String::new();
+///
+/// let a = MorseWord::try_from('a').unwrap();
+/// notation.write_sequences(&mut rendered, a.to_sequence().1.into_iter().take(3)).unwrap();
+///
+/// assert_eq!(rendered, "=.===");
+///
+/// let periods: Result<Vec<_>, _> = notation.parse("=.===...=").collect();
+///
+/// assert_eq!(
+///     periods.unwrap(),
+///     [Period::On(1), Period::Off(1), Period::On(3), Period::Off(3), Period::On(1)]
+/// );
+///
+/// assert_eq!(notation.is_canonical("=.===...="), Ok(true));
+/// assert_eq!(notation.is_canonical("=.====..="), Ok(false));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitNotation {
+    mark: char,
+    space: char,
+}
+
+impl UnitNotation {
+    /// Marks written as `=` and spaces as `.`.
+    pub const STANDARD: UnitNotation = UnitNotation {
+        mark: '=',
+        space: '.',
+    };
+
+    /// Create a notation with custom glyphs, such as `'1'` and `'0'`.
+    pub const fn new(mark: char, space: char) -> Self {
+        UnitNotation { mark, space }
+    }
+
+    /// The glyph for a time unit during which the output is on.
+    pub const fn mark(&self) -> char {
+        self.mark
+    }
+
+    /// The glyph for a time unit during which the output is off.
+    pub const fn space(&self) -> char {
+        self.space
+    }
+
+    /// Write a period as one glyph per time unit.
+    pub fn write_period(&self, w: &mut impl fmt::Write, period: Period) -> fmt::Result {
+        let (glyph, units) = match period {
+            Period::On(units) => (self.mark, units),
+            Period::Off(units) => (self.space, units),
+        };
+
+        (0..units).try_for_each(|_| w.write_char(glyph))
+    }
+
+    /// Write a timeline of periods.
+    pub fn write_periods(
+        &self,
+        w: &mut impl fmt::Write,
+        periods: impl IntoIterator<Item = Period>,
+    ) -> fmt::Result {
+        periods
+            .into_iter()
+            .try_for_each(|period| self.write_period(w, period))
+    }
+
+    /// Write a stream of [MorseSequence] values, in which every dot and pause
+    /// takes one time unit and every dash three.
+    pub fn write_sequences(
+        &self,
+        w: &mut impl fmt::Write,
+        sequences: impl IntoIterator<Item = MorseSequence>,
+    ) -> fmt::Result {
+        sequences.into_iter().try_for_each(|sequence| {
+            let period = match sequence {
+                MorseSequence::Code(MorseCode::Dot) => Period::On(1),
+                MorseSequence::Code(MorseCode::Dash) => Period::On(3),
+                MorseSequence::Pause => Period::Off(1),
+            };
+
+            self.write_period(w, period)
+        })
+    }
+
+    /// Parse a string in this notation into its periods. Consecutive glyphs
+    /// of the same kind make up a single period.
+    pub fn parse<'a>(&self, notation: &'a str) -> UnitNotationParser<'a> {
+        UnitNotationParser {
+            notation: *self,
+            chars: notation.char_indices(),
+            pending: None,
+            done: false,
+        }
+    }
+
+    /// Returns true if every mark in `notation` is 1 or 3 time units long,
+    /// and every space 1, 3 or 7 time units, as standard Morse timing
+    /// prescribes.
+    pub fn is_canonical(&self, notation: &str) -> Result<bool, UnitNotationError> {
+        let mut canonical = true;
+
+        for period in self.parse(notation) {
+            canonical &= matches!(period?, Period::On(1 | 3) | Period::Off(1 | 3 | 7));
+        }
+
+        Ok(canonical)
+    }
+}
+
+impl Default for UnitNotation {
+    fn default() -> Self {
+        UnitNotation::STANDARD
+    }
+}
+
+/// An iterator over the periods of a string in unit-level timing notation,
+/// created by [UnitNotation::parse].
+///
+/// The iterator returns an error at the first symbol that is neither a mark
+/// nor a space, and ends after it.
+#[derive(Debug, Clone)]
+pub struct UnitNotationParser<'a> {
+    notation: UnitNotation,
+    chars: CharIndices<'a>,
+    /// The period that is being counted.
+    pending: Option<Period>,
+    done: bool,
+}
+
+impl Iterator for UnitNotationParser<'_> {
+    type Item = Result<Period, UnitNotationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        for (byte_offset, symbol) in self.chars.by_ref() {
+            let on = if symbol == self.notation.mark {
+                true
+            } else if symbol == self.notation.space {
+                false
+            } else {
+                self.done = true;
+                return Some(Err(UnitNotationError {
+                    symbol,
+                    byte_offset,
+                }));
+            };
+
+            match (&mut self.pending, on) {
+                (Some(Period::On(units)), true) | (Some(Period::Off(units)), false) => {
+                    *units += 1;
+                }
+                (pending, on) => {
+                    let next = match on {
+                        true => Period::On(1),
+                        false => Period::Off(1),
+                    };
+
+                    if let Some(period) = pending.replace(next) {
+                        return Some(Ok(period));
+                    }
+                }
+            }
+        }
+
+        self.done = true;
+        self.pending.take().map(Ok)
+    }
+}
+
+impl FusedIterator for UnitNotationParser<'_> {}
@@ -1,3 +1,5 @@
+use core::fmt;
+
 use crate::{MorseCode, MorseSequence};
 
 /// A struct representing a single morse code sequence that maps
@@ -29,8 +31,27 @@ from_word!(4);
 from_word!(5);
 
 impl MorseWord {
-    const fn new<const N: usize>(codes: [MorseCode; N]) -> Self {
-        debug_assert!(N <= 5);
+    /// Creates a [MorseWord] from an array of [MorseCode] values.
+    ///
+    /// Unlike the [From] implementations, this can be used in const context,
+    /// which is what the `morse!` macro expands to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty or contains more than 5 values. In const
+    /// context, this is a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse_core::{MorseCode, MorseWord};
+    ///
+    /// const A: MorseWord = MorseWord::new([MorseCode::Dot, MorseCode::Dash]);
+    ///
+    /// assert_eq!(A.len(), 2);
+    /// ```
+    pub const fn new<const N: usize>(codes: [MorseCode; N]) -> Self {
+        assert!(N >= 1 && N <= 5, "a MorseWord holds between 1 and 5 codes");
 
         // The first three bits are used to store the word length
         let mut code: u8 = (N as u8) & 0b0000_0111;
@@ -74,7 +95,9 @@ impl MorseWord {
     /// # Examples
     ///
     /// ```
-    /// let word = morse!(..-);
+    /// use megamorse_core::{MorseCode, MorseWord};
+    ///
+    /// let word = MorseWord::from([MorseCode::Dot, MorseCode::Dot, MorseCode::Dash]);
     ///
     /// let (len, codes) = word.to_array();
     ///
@@ -83,13 +106,22 @@ impl MorseWord {
     /// assert_eq!(codes[0], MorseCode::Dot);
     /// assert_eq!(codes[1], MorseCode::Dot);
     /// assert_eq!(codes[2], MorseCode::Dash);
+    ///
+    /// let six = MorseWord::try_from('6').unwrap();
+    ///
+    /// assert_eq!(
+    ///     six.to_array(),
+    ///     (5, [MorseCode::Dash, MorseCode::Dot, MorseCode::Dot, MorseCode::Dot, MorseCode::Dot])
+    /// );
     /// ````
     pub const fn to_array(self) -> (usize, [MorseCode; 5]) {
         let mut codes = [MorseCode::Dot; 5];
         let n = self.len();
+        // The codes are stored above the three length bits
+        let bits = self.code >> 3;
 
         if n >= 1 {
-            codes[0] = if self.code & 0b0000_0001 == 0 {
+            codes[0] = if bits & 0b0000_0001 == 0 {
                 MorseCode::Dot
             } else {
                 MorseCode::Dash
@@ -97,7 +129,7 @@ impl MorseWord {
         }
 
         if n >= 2 {
-            codes[1] = if self.code & 0b0000_0010 == 0 {
+            codes[1] = if bits & 0b0000_0010 == 0 {
                 MorseCode::Dot
             } else {
                 MorseCode::Dash
@@ -105,7 +137,7 @@ impl MorseWord {
         }
 
         if n >= 3 {
-            codes[2] = if self.code & 0b0000_0100 == 0 {
+            codes[2] = if bits & 0b0000_0100 == 0 {
                 MorseCode::Dot
             } else {
                 MorseCode::Dash
@@ -113,7 +145,7 @@ impl MorseWord {
         }
 
         if n >= 4 {
-            codes[3] = if self.code & 0b0000_1000 == 0 {
+            codes[3] = if bits & 0b0000_1000 == 0 {
                 MorseCode::Dot
             } else {
                 MorseCode::Dash
@@ -121,7 +153,7 @@ impl MorseWord {
         }
 
         if n == 5 {
-            codes[4] = if self.code & 0b0001_0000 == 0 {
+            codes[4] = if bits & 0b0001_0000 == 0 {
                 MorseCode::Dot
             } else {
                 MorseCode::Dash
@@ -131,6 +163,72 @@ impl MorseWord {
         (n, codes)
     }
 
+    /// Creates a [MorseWord] from a slice of [MorseCode] values whose length
+    /// is only known at runtime.
+    ///
+    /// Returns None if the slice is empty or contains more than 5 values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse_core::{MorseCode, MorseWord};
+    ///
+    /// let codes = [MorseCode::Dot, MorseCode::Dash];
+    ///
+    /// assert_eq!(MorseWord::from_slice(&codes), Some(MorseWord::new(codes)));
+    /// assert_eq!(MorseWord::from_slice(&[]), None);
+    /// ```
+    pub const fn from_slice(codes: &[MorseCode]) -> Option<Self> {
+        if codes.is_empty() || codes.len() > 5 {
+            return None;
+        }
+
+        let mut code = codes.len() as u8;
+        let mut i = 0;
+
+        while i < codes.len() {
+            code |= codes[i].to_bit() << (3 + i);
+            i += 1;
+        }
+
+        Some(MorseWord { code })
+    }
+
+    /// Returns the compact single-byte representation of the [MorseWord].
+    ///
+    /// The lowest 3 bits hold the amount of codes, and the bits above them
+    /// the codes themselves, starting with the first code: `0` for a dot and
+    /// `1` for a dash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse_core::MorseWord;
+    ///
+    /// let a = MorseWord::try_from('a').unwrap();
+    ///
+    /// assert_eq!(a.to_bits(), 0b10_010);
+    /// assert_eq!(MorseWord::from_bits(0b10_010), Some(a));
+    /// ```
+    pub const fn to_bits(self) -> u8 {
+        self.code
+    }
+
+    /// Creates a [MorseWord] from the representation returned by
+    /// [MorseWord::to_bits].
+    ///
+    /// Returns None if the byte holds less than 1 or more than 5 codes, or if
+    /// any bit above the last code is set.
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        let len = bits & 0b0000_0111;
+
+        if len == 0 || len > 5 || (bits as u16) >> (3 + len) != 0 {
+            return None;
+        }
+
+        Some(MorseWord { code: bits })
+    }
+
     /// Returns the amount of [MorseCode] values in the [MorseWord].
     pub const fn len(&self) -> usize {
         debug_assert!(self.code & 0b0000_0111 <= 5);
@@ -190,180 +288,243 @@ impl MorseWord {
 
         (seq_len, sequence)
     }
-}
 
-/// Converts a single [char] to a [MorseWord].
-/// Is used both internally by the megamorse library and can be used
-/// by the user to convert a single character to a [MorseWord], which
-/// can then be played by a player.
-///
-/// Will return an error if the character has no Morse code representation.
-impl TryFrom<char> for MorseWord {
-    type Error = ();
-
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        let as_lower = value.to_ascii_lowercase();
-
-        let word = match as_lower {
-            'a' => MorseWord::from([MorseCode::Dot, MorseCode::Dash]),
-            'b' => MorseWord::from([
+    /// Converts a single [char] to a [MorseWord], like the [TryFrom]
+    /// implementation, but usable in const context.
+    ///
+    /// Returns None if the character has no Morse code representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse_core::{MorseCode, MorseWord};
+    ///
+    /// const K: Option<MorseWord> = MorseWord::from_char_const('k');
+    ///
+    /// assert_eq!(K, Some(MorseWord::new([MorseCode::Dash, MorseCode::Dot, MorseCode::Dash])));
+    /// assert_eq!(MorseWord::from_char_const('!'), None);
+    /// ```
+    pub const fn from_char_const(c: char) -> Option<Self> {
+        let word = match c.to_ascii_lowercase() {
+            'a' => MorseWord::new([MorseCode::Dot, MorseCode::Dash]),
+            'b' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            'c' => MorseWord::from([
+            'c' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dot,
             ]),
-            'd' => MorseWord::from([MorseCode::Dash, MorseCode::Dot, MorseCode::Dot]),
-            'e' => MorseWord::from([MorseCode::Dot]),
-            'f' => MorseWord::from([
+            'd' => MorseWord::new([MorseCode::Dash, MorseCode::Dot, MorseCode::Dot]),
+            'e' => MorseWord::new([MorseCode::Dot]),
+            'f' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dot,
             ]),
-            'g' => MorseWord::from([MorseCode::Dash, MorseCode::Dash, MorseCode::Dot]),
-            'h' => MorseWord::from([
+            'g' => MorseWord::new([MorseCode::Dash, MorseCode::Dash, MorseCode::Dot]),
+            'h' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            'i' => MorseWord::from([MorseCode::Dot, MorseCode::Dot]),
-            'j' => MorseWord::from([
+            'i' => MorseWord::new([MorseCode::Dot, MorseCode::Dot]),
+            'j' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
             ]),
-            'k' => MorseWord::from([MorseCode::Dash, MorseCode::Dot, MorseCode::Dash]),
-            'l' => MorseWord::from([
+            'k' => MorseWord::new([MorseCode::Dash, MorseCode::Dot, MorseCode::Dash]),
+            'l' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            'm' => MorseWord::from([MorseCode::Dash, MorseCode::Dash]),
-            'n' => MorseWord::from([MorseCode::Dash, MorseCode::Dot]),
-            'o' => MorseWord::from([MorseCode::Dash, MorseCode::Dash, MorseCode::Dash]),
-            'p' => MorseWord::from([
+            'm' => MorseWord::new([MorseCode::Dash, MorseCode::Dash]),
+            'n' => MorseWord::new([MorseCode::Dash, MorseCode::Dot]),
+            'o' => MorseWord::new([MorseCode::Dash, MorseCode::Dash, MorseCode::Dash]),
+            'p' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dot,
             ]),
-            'q' => MorseWord::from([
+            'q' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dash,
             ]),
-            'r' => MorseWord::from([MorseCode::Dot, MorseCode::Dash, MorseCode::Dot]),
-            's' => MorseWord::from([MorseCode::Dot, MorseCode::Dot, MorseCode::Dot]),
-            't' => MorseWord::from([MorseCode::Dash]),
-            'u' => MorseWord::from([MorseCode::Dot, MorseCode::Dot, MorseCode::Dash]),
-            'v' => MorseWord::from([
+            'r' => MorseWord::new([MorseCode::Dot, MorseCode::Dash, MorseCode::Dot]),
+            's' => MorseWord::new([MorseCode::Dot, MorseCode::Dot, MorseCode::Dot]),
+            't' => MorseWord::new([MorseCode::Dash]),
+            'u' => MorseWord::new([MorseCode::Dot, MorseCode::Dot, MorseCode::Dash]),
+            'v' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dash,
             ]),
-            'w' => MorseWord::from([MorseCode::Dot, MorseCode::Dash, MorseCode::Dash]),
-            'x' => MorseWord::from([
+            'w' => MorseWord::new([MorseCode::Dot, MorseCode::Dash, MorseCode::Dash]),
+            'x' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dash,
             ]),
-            'y' => MorseWord::from([
+            'y' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dash,
             ]),
-            'z' => MorseWord::from([
+            'z' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            '0' => MorseWord::from([
+            '0' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
             ]),
-            '1' => MorseWord::from([
+            '1' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
             ]),
-            '2' => MorseWord::from([
+            '2' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
             ]),
-            '3' => MorseWord::from([
+            '3' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dash,
                 MorseCode::Dash,
             ]),
-            '4' => MorseWord::from([
+            '4' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dash,
             ]),
-            '5' => MorseWord::from([
+            '5' => MorseWord::new([
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            '6' => MorseWord::from([
+            '6' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            '7' => MorseWord::from([
+            '7' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            '8' => MorseWord::from([
+            '8' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dot,
                 MorseCode::Dot,
             ]),
-            '9' => MorseWord::from([
+            '9' => MorseWord::new([
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dash,
                 MorseCode::Dot,
             ]),
-            _ => return Err(()),
+            _ => return None,
         };
 
-        Ok(word)
+        Some(word)
+    }
+
+    /// Returns the character this [MorseWord] represents, as an uppercase
+    /// letter or a digit, or None if it represents no supported character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use megamorse_core::{MorseCode, MorseWord};
+    ///
+    /// let word = MorseWord::from([MorseCode::Dash, MorseCode::Dot, MorseCode::Dash]);
+    ///
+    /// assert_eq!(word.to_char(), Some('K'));
+    /// assert_eq!(MorseWord::try_from('k').unwrap().to_char(), Some('K'));
+    /// ```
+    pub fn to_char(self) -> Option<char> {
+        ('A'..='Z')
+            .chain('0'..='9')
+            .find(|&c| MorseWord::try_from(c) == Ok(self))
+    }
+}
+
+/// Converts a single [char] to a [MorseWord].
+/// Is used both internally by the megamorse library and can be used
+/// by the user to convert a single character to a [MorseWord], which
+/// can then be played by a player.
+///
+/// Will return an [InvalidCharacterError] if the character has no Morse code
+/// representation.
+impl TryFrom<char> for MorseWord {
+    type Error = InvalidCharacterError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        MorseWord::from_char_const(value).ok_or(InvalidCharacterError { character: value })
     }
 }
+
+/// Error returned when converting a [char] that has no Morse code
+/// representation to a [MorseWord].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCharacterError {
+    character: char,
+}
+
+impl InvalidCharacterError {
+    /// The character that could not be converted.
+    pub const fn character(&self) -> char {
+        self.character
+    }
+}
+
+impl fmt::Display for InvalidCharacterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "character {:?} has no Morse code representation",
+            self.character
+        )
+    }
+}
+
+impl core::error::Error for InvalidCharacterError {}
@@ -0,0 +1,90 @@
+//! Round trips of every serializable type through a human-readable format
+//! (JSON) and a binary format (postcard).
+#![cfg(feature = "serde")]
+
+use core::fmt::Debug;
+
+use megamorse_core::MorseCode;
+use megamorse_core::MorseSequence;
+use megamorse_core::MorseWord;
+use megamorse_core::Period;
+use megamorse_core::Prosign;
+use megamorse_core::UnitNotation;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Checks that `value` serializes to `json` and to `bytes`, and that both
+/// deserialize back to `value`.
+fn round_trip<T>(value: T, json: &str, bytes: &[u8])
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+
+    let mut buffer = [0; 64];
+
+    assert_eq!(postcard::to_slice(&value, &mut buffer).unwrap(), bytes);
+    assert_eq!(postcard::from_bytes::<T>(bytes).unwrap(), value);
+}
+
+#[test]
+fn morse_code() {
+    round_trip(MorseCode::Dot, r#"".""#, &[0]);
+    round_trip(MorseCode::Dash, r#""-""#, &[1]);
+
+    assert!(serde_json::from_str::<MorseCode>(r#""x""#).is_err());
+    assert!(postcard::from_bytes::<MorseCode>(&[2]).is_err());
+}
+
+#[test]
+fn morse_sequence() {
+    round_trip(
+        [
+            MorseSequence::Code(MorseCode::Dot),
+            MorseSequence::Pause,
+            MorseSequence::Code(MorseCode::Dash),
+        ],
+        r#"["."," ","-"]"#,
+        &[0, 2, 1],
+    );
+
+    assert!(postcard::from_bytes::<MorseSequence>(&[3]).is_err());
+}
+
+#[test]
+fn morse_word() {
+    let a = MorseWord::try_from('a').unwrap();
+
+    round_trip(a, r#"".-""#, &[a.to_bits()]);
+}
+
+#[test]
+fn prosign() {
+    let s = MorseWord::try_from('s').unwrap();
+    let k = MorseWord::try_from('k').unwrap();
+
+    round_trip(
+        Prosign::SK,
+        r#"["...","-.-"]"#,
+        &[2, s.to_bits(), k.to_bits()],
+    );
+}
+
+#[test]
+fn period() {
+    round_trip(
+        [Period::On(3), Period::Off(1)],
+        r#"[{"On":3},{"Off":1}]"#,
+        &[0, 3, 1, 1],
+    );
+}
+
+#[test]
+fn unit_notation() {
+    round_trip(
+        UnitNotation::new('1', '0'),
+        r#"{"mark":"1","space":"0"}"#,
+        &[1, b'1', 1, b'0'],
+    );
+}
@@ -3,7 +3,7 @@
 //!
 //! # Examples
 //!
-//! ```
+//! ```ignore
 //! let words = morse!(... ___ ...);
 //!
 //! for word in words.into_iter() {
@@ -49,7 +49,7 @@ pub fn morse(item: TokenStream) -> TokenStream {
             }
         }
         let joined = codes.join(", ");
-        let full = format!("megamorse::MorseWord::from([{}])", joined);
+        let full = format!("megamorse::MorseWord::new([{}])", joined);
 
         parsed_words.push(full);
     }